@@ -0,0 +1,206 @@
+use std::path::Path;
+
+use anyhow::{Context, bail};
+use clap::Subcommand;
+
+use crate::cmd::check::changelog::{Timestamp, added_items, cat_order, entry_timestamp};
+use crate::cmd::check::{find_spec_dir, rel_path};
+use crate::cmd::commit::parse_subject;
+use crate::config;
+
+#[derive(clap::Args)]
+pub struct Args {
+	#[command(subcommand)]
+	pub command: ChangelogCommand,
+}
+
+#[derive(Subcommand)]
+pub enum ChangelogCommand {
+	/// Insert a new entry generated from commits since the one that added the
+	/// last entry
+	Add,
+}
+
+pub fn execute(args: Args) -> anyhow::Result<()> {
+	match args.command {
+		ChangelogCommand::Add => add(),
+	}
+}
+
+fn add() -> anyhow::Result<()> {
+	let spec_dir = find_spec_dir()?;
+	let repo_root = spec_dir.parent().context("spec dir has no parent")?;
+	let path = spec_dir.join("CHANGELOG.md");
+	let content =
+		std::fs::read_to_string(&path).with_context(|| format!("cannot read {}", path.display()))?;
+
+	// the newest entry is the first one in the file
+	let last = match content.lines().find(|l| l.starts_with("## [")) {
+		Some(line) => {
+			let ts = entry_timestamp(line)
+				.and_then(Timestamp::parse)
+				.with_context(|| format!("cannot parse latest entry: {line}"))?;
+			Some(ts)
+		}
+		None => None,
+	};
+
+	// select by ancestry from the commit that added the last entry rather
+	// than by date, since rebasing gives old commits new committer dates
+	let rel = rel_path(&path, repo_root);
+	let revset = match last {
+		Some(ts) => {
+			format!("(latest(::@ & diff_contains(\"## [{ts}]\", root-file:\"{rel}\"))..@) & ~empty()")
+		}
+		None => "::@ & ~empty()".to_string(),
+	};
+	let log = crate::jj::output(
+		repo_root,
+		&[
+			"log",
+			"--no-graph",
+			"--reversed",
+			"-r",
+			&revset,
+			"-T",
+			r#"description.first_line() ++ "\n""#,
+		],
+	)?;
+
+	let mut categories: Vec<(&str, Vec<String>)> = Vec::new();
+	for subject in log.lines() {
+		let Some(parsed) = parse_subject(subject) else {
+			continue;
+		};
+		let Some(category) = category_for(parsed.kind) else {
+			continue;
+		};
+		let item = format!("{}: {}", parsed.scope, parsed.description);
+		match categories.iter_mut().find(|(name, _)| *name == category) {
+			Some((_, items)) => items.push(item),
+			None => categories.push((category, vec![item])),
+		}
+	}
+
+	if categories.is_empty() {
+		match last {
+			Some(ts) => bail!("no break/add/change/fix/rm commits since {ts}"),
+			None => bail!("no break/add/change/fix/rm commits found"),
+		}
+	}
+	categories.sort_by_key(|(name, _)| cat_order(name));
+
+	let now = Timestamp::now();
+	let mut entry = format!("## [{now}]\n");
+	for (name, items) in &categories {
+		entry.push_str(&format!("\n### {name}\n\n"));
+		for item in items {
+			entry.push_str(&format!("- {item}\n"));
+		}
+	}
+
+	let updated = insert_entry(&content, &entry);
+	std::fs::write(&path, updated).with_context(|| format!("cannot write {}", path.display()))?;
+
+	let count: usize = categories.iter().map(|(_, items)| items.len()).sum();
+	eprintln!(
+		"added entry [{now}] with {count} item(s) to {} — review before committing",
+		path.display()
+	);
+	Ok(())
+}
+
+/// With `[vcs] require_changelog = true`, a `break` or `rm` commit must add a
+/// `### Breaking` or `### Removed` item to the CHANGELOG between `from` and `to`.
+/// When `files` is not empty only those filesets go into the commit (as with
+/// `qwq commit -- <files>`), so a CHANGELOG edit outside them does not count.
+pub fn require_documented(
+	subject: &str,
+	from: &str,
+	to: &str,
+	files: &[String],
+) -> anyhow::Result<()> {
+	let category = match parse_subject(subject).map(|s| s.kind) {
+		Some("break") => "Breaking",
+		Some("rm") => "Removed",
+		_ => return Ok(()),
+	};
+	let enabled = config::load_table("vcs")?
+		.and_then(|t| t.get_bool("require_changelog"))
+		.unwrap_or(false);
+	if !enabled {
+		return Ok(());
+	}
+
+	let spec_dir = find_spec_dir()?;
+	let repo_root = spec_dir.parent().context("spec dir has no parent")?;
+	let rel = rel_path(&spec_dir.join("CHANGELOG.md"), repo_root);
+
+	let old = crate::jj::file_at(repo_root, from, &rel)?.unwrap_or_default();
+	let new = if files.is_empty() || selected_files(repo_root, from, to, files)?.contains(&rel) {
+		crate::jj::file_at(repo_root, to, &rel)?.unwrap_or_default()
+	} else {
+		old.clone()
+	};
+	if added_items(&old, &new, category).is_empty() {
+		bail!("{category} changes must add a `### {category}` item to {rel}.\n  subject: {subject}");
+	}
+	Ok(())
+}
+
+/// Repo-relative paths changed between `from` and `to` within the filesets the
+/// user passed, which jj resolves against the current directory.
+fn selected_files(
+	repo_root: &Path,
+	from: &str,
+	to: &str,
+	files: &[String],
+) -> anyhow::Result<Vec<String>> {
+	let cwd = std::env::current_dir().context("cannot determine current directory")?;
+	let mut args = vec!["diff", "--from", from, "--to", to, "--name-only", "--"];
+	args.extend(files.iter().map(String::as_str));
+	let out = crate::jj::output(&cwd, &args)?;
+	Ok(
+		out
+			.lines()
+			.filter(|l| !l.is_empty())
+			.map(|l| rel_path(&cwd.join(l), repo_root))
+			.collect(),
+	)
+}
+
+/// Map a commit type onto its CHANGELOG category; other types are not user-facing.
+fn category_for(kind: &str) -> Option<&'static str> {
+	match kind {
+		"break" => Some("Breaking"),
+		"add" => Some("Added"),
+		"change" => Some("Changed"),
+		"fix" => Some("Fixed"),
+		"rm" => Some("Removed"),
+		_ => None,
+	}
+}
+
+/// Place `entry` above the newest existing entry, or at the end if there is none.
+fn insert_entry(content: &str, entry: &str) -> String {
+	let mut result = String::new();
+	let mut inserted = false;
+
+	for line in content.lines() {
+		if !inserted && line.starts_with("## [") {
+			result.push_str(entry);
+			result.push('\n');
+			inserted = true;
+		}
+		result.push_str(line);
+		result.push('\n');
+	}
+
+	if !inserted {
+		if !result.ends_with("\n\n") {
+			result.push('\n');
+		}
+		result.push_str(entry);
+	}
+	result
+}
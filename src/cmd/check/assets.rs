@@ -0,0 +1,219 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use super::links::{link_destination, normalize, resolve_target};
+use super::{collect_files, glob_match, rel_path};
+use crate::config;
+
+/// Extensions treated as assets unless `[check.assets] extensions` says otherwise.
+const ASSET_EXTENSIONS: &[&str] = &[
+	"png", "jpg", "jpeg", "gif", "svg", "webp", "avif", "bmp", "ico", "pdf", "mp4", "webm", "zip",
+];
+
+/// `[check.assets]`.
+struct Settings {
+	extensions: Vec<String>,
+	/// Largest allowed asset in KiB, if limited.
+	max_size_kb: Option<usize>,
+	/// Repo-relative globs of assets that need no reference.
+	unreferenced_ok: Vec<String>,
+}
+
+impl Settings {
+	fn load() -> anyhow::Result<Self> {
+		let table = config::load_table("check.assets")?;
+		let list = |key: &str| table.as_ref().and_then(|t| t.get_list(key));
+		Ok(Settings {
+			extensions: list("extensions")
+				.unwrap_or_else(|| ASSET_EXTENSIONS.iter().map(|s| s.to_string()).collect()),
+			max_size_kb: table.as_ref().and_then(|t| t.get_usize("max_size_kb")),
+			unreferenced_ok: list("unreferenced_ok").unwrap_or_default(),
+		})
+	}
+
+	fn is_asset(&self, path: &Path) -> bool {
+		path.extension().is_some_and(|ext| {
+			let ext = ext.to_string_lossy().to_lowercase();
+			self.extensions.contains(&ext)
+		})
+	}
+}
+
+pub fn run(spec_dir: &Path, repo_root: &Path) -> anyhow::Result<Vec<String>> {
+	let settings = Settings::load()?;
+	let root = normalize(repo_root);
+	let files = collect_files(spec_dir)?;
+	let mut referenced: HashSet<PathBuf> = HashSet::new();
+	let mut errors = Vec::new();
+
+	for file in files
+		.iter()
+		.filter(|f| f.extension().is_some_and(|e| e == "md"))
+	{
+		let content = std::fs::read_to_string(file)?;
+		let dir = file.parent().unwrap_or(spec_dir);
+		let mut in_code_block = false;
+
+		for (line_num, line) in content.lines().enumerate() {
+			in_code_block = super::is_in_code_block(line, in_code_block);
+			if in_code_block || line.starts_with("```") {
+				continue;
+			}
+			let location = format!("{}:{}", file.display(), line_num + 1);
+
+			for target in references(line) {
+				let target_path = target.split('#').next().unwrap_or(target);
+				if let Ok(resolved) = resolve_target(dir, &root, target_path) {
+					referenced.insert(resolved);
+				}
+			}
+
+			for image in images(line) {
+				if image.alt.trim().is_empty() {
+					errors.push(format!("MISSING ALT TEXT: {location} -> {}", image.src));
+				}
+				if image.src.starts_with("http://") || image.src.starts_with("https://") {
+					continue;
+				}
+				if let Err((kind, note)) = resolve_target(dir, &root, image.src) {
+					let kind = if kind == "BROKEN LINK" {
+						"MISSING IMAGE"
+					} else {
+						kind
+					};
+					errors.push(format!("{kind}: {location} -> {}{note}", image.src));
+				}
+			}
+		}
+	}
+
+	for asset in files.iter().filter(|f| settings.is_asset(f)) {
+		let rel = rel_path(asset, repo_root);
+
+		if let Some(max_kb) = settings.max_size_kb {
+			let size = std::fs::metadata(asset)?.len();
+			if size > max_kb as u64 * 1024 {
+				errors.push(format!(
+					"ASSET TOO LARGE: {} ({} KiB, max {max_kb} KiB)",
+					asset.display(),
+					size.div_ceil(1024)
+				));
+			}
+		}
+
+		let ok = settings.unreferenced_ok.iter().any(|g| glob_match(g, &rel));
+		if !ok && !referenced.contains(&normalize(asset)) {
+			errors.push(format!("UNREFERENCED ASSET: {}", asset.display()));
+		}
+	}
+
+	Ok(errors)
+}
+
+struct Image<'a> {
+	alt: &'a str,
+	src: &'a str,
+}
+
+/// `![alt](src)` images and `<img src=... alt=...>` tags on one line.
+fn images(line: &str) -> Vec<Image<'_>> {
+	let mut found = Vec::new();
+
+	let mut rest = line;
+	while let Some(start) = rest.find("![") {
+		let after = &rest[start + 2..];
+		let Some(mid) = after.find("](") else {
+			break;
+		};
+		let dest = &after[mid + 2..];
+		let Some(end) = dest.find(')') else {
+			break;
+		};
+		found.push(Image {
+			alt: &after[..mid],
+			src: link_destination(&dest[..end]),
+		});
+		rest = &dest[end + 1..];
+	}
+
+	let mut rest = line;
+	while let Some(start) = rest.find("<img") {
+		let tag = &rest[start..];
+		let end = tag.find('>').unwrap_or(tag.len());
+		if let Some(src) = attribute(&tag[..end], "src") {
+			found.push(Image {
+				alt: attribute(&tag[..end], "alt").unwrap_or_default(),
+				src,
+			});
+		}
+		rest = &tag[end..];
+	}
+
+	found
+}
+
+/// Every link, image and `<img src>` target on one line.
+fn references(line: &str) -> Vec<&str> {
+	let mut targets: Vec<&str> = images(line).into_iter().map(|i| i.src).collect();
+
+	let mut rest = line;
+	while let Some(start) = rest.find("](") {
+		let after = &rest[start + 2..];
+		let Some(end) = after.find(')') else {
+			break;
+		};
+		targets.push(link_destination(&after[..end]));
+		rest = &after[end + 1..];
+	}
+	targets
+}
+
+/// Value of `name="..."` or `name='...'` inside an HTML tag.
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+	let mut rest = tag;
+	while let Some(pos) = rest.find(name) {
+		let before_ok = rest[..pos].ends_with(char::is_whitespace);
+		let after = rest[pos + name.len()..].trim_start();
+		rest = &rest[pos + name.len()..];
+		let Some(value) = after.strip_prefix('=').map(str::trim_start) else {
+			continue;
+		};
+		if !before_ok {
+			continue;
+		}
+		let quote = value.chars().next()?;
+		if quote == '"' || quote == '\'' {
+			let value = &value[1..];
+			return value.find(quote).map(|end| &value[..end]);
+		}
+		return value.split(|c: char| c.is_whitespace() || c == '>').next();
+	}
+	None
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn markdown_images() {
+		let found = images("See ![Flow](img/flow.png \"t\") and ![](x.svg).");
+		let pairs: Vec<(&str, &str)> = found.iter().map(|i| (i.alt, i.src)).collect();
+		assert_eq!(pairs, [("Flow", "img/flow.png"), ("", "x.svg")]);
+	}
+
+	#[test]
+	fn html_images() {
+		let found = images(r#"<img src="a.png" alt="A"> <img data-alt="x" src='b.png'>"#);
+		let pairs: Vec<(&str, &str)> = found.iter().map(|i| (i.alt, i.src)).collect();
+		assert_eq!(pairs, [("A", "a.png"), ("", "b.png")]);
+	}
+
+	#[test]
+	fn all_references() {
+		assert_eq!(
+			references("[doc](a.pdf) ![x](b.png)"),
+			["b.png", "a.pdf", "b.png"]
+		);
+	}
+}
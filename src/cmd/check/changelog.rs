@@ -18,60 +18,325 @@ pub fn run(spec_dir: &Path) -> anyhow::Result<Vec<String>> {
 		errors.push("FORMAT: spec/CHANGELOG.md missing '# Changelog' header".to_string());
 	}
 
-	// check version entries and category order
-	let mut last_cat_order: u8 = 0;
-	let mut current_version = String::new();
+	let now = Timestamp::now().to_unix();
+	let mut previous: Option<(&str, Timestamp)> = None;
+	let mut seen: Vec<Timestamp> = Vec::new();
 
-	for line in &lines {
-		if line.starts_with("## [") {
-			current_version = line.to_string();
-			last_cat_order = 0;
+	for entry in parse_entries(&content) {
+		let version = entry.header;
 
-			if !is_valid_timestamp_entry(line) {
-				errors.push(format!("FORMAT: invalid version entry: {line}"));
-			}
-			continue;
+		// check timestamp syntax, calendar validity and ordering
+		match entry_timestamp(version) {
+			None => errors.push(format!("FORMAT: invalid version entry: {version}")),
+			Some(ts) => match Timestamp::parse(ts) {
+				None => errors.push(format!("DATE: invalid date or time in {version}")),
+				Some(ts) => {
+					if ts.to_unix() > now {
+						errors.push(format!("DATE: {version} is in the future"));
+					}
+					if seen.contains(&ts) {
+						errors.push(format!("DUPLICATE: entry {version} appears more than once"));
+					} else if let Some((prev, prev_ts)) = previous
+						&& ts > prev_ts
+					{
+						errors.push(format!(
+							"ORDER: {version} is newer than {prev} above it (entries must be newest first)"
+						));
+					}
+					seen.push(ts);
+					previous = Some((version, ts));
+				}
+			},
 		}
 
-		if let Some(category) = line.strip_prefix("### ") {
-			let order = cat_order(category);
+		// check category names, order, uniqueness and content
+		if entry.categories.is_empty() {
+			errors.push(format!("EMPTY: {version} has no categories"));
+		}
+
+		let mut last_cat_order: u8 = 0;
+		for (i, category) in entry.categories.iter().enumerate() {
+			let name = category.name;
+			let order = cat_order(name);
 
 			if order == 0 {
+				errors.push(format!("FORMAT: unknown category '{name}' in {version}"));
+			} else if entry.categories[..i].iter().any(|c| c.name == name) {
 				errors.push(format!(
-					"FORMAT: unknown category '{category}' in {current_version}"
+					"DUPLICATE: category '{name}' repeated in {version}"
 				));
 			} else if order < last_cat_order {
 				errors.push(format!(
-					"FORMAT: wrong category order '{category}' in {current_version} \
+					"FORMAT: wrong category order '{name}' in {version} \
 					 (expected: Breaking, Added, Changed, Fixed, Removed)"
 				));
 			}
-			last_cat_order = order;
+			last_cat_order = last_cat_order.max(order);
+
+			if category.items.is_empty() {
+				errors.push(format!(
+					"EMPTY: category '{name}' in {version} has no items"
+				));
+			}
 		}
 	}
 
 	Ok(errors)
 }
 
-fn is_valid_timestamp_entry(line: &str) -> bool {
-	// ## [2026-02-10T16:16:38Z]
-	let Some(rest) = line.strip_prefix("## [") else {
-		return false;
-	};
-	let Some(ts) = rest.strip_suffix(']') else {
-		return false;
+/// Released entries are immutable: compared with the `main` bookmark, every
+/// existing entry must be unchanged and new entries may only appear above them.
+pub fn run_vcs(spec_dir: &Path, repo_root: &Path) -> anyhow::Result<Vec<String>> {
+	let rel = super::rel_path(&spec_dir.join("CHANGELOG.md"), repo_root);
+	let mut errors = Vec::new();
+
+	let Some(old_content) = crate::jj::file_at(repo_root, "main", &rel)? else {
+		return Ok(errors);
 	};
+	let new_content = std::fs::read_to_string(spec_dir.join("CHANGELOG.md")).unwrap_or_default();
+	let old_entries = parse_entries(&old_content);
+	let new_entries = parse_entries(&new_content);
+
+	for old in &old_entries {
+		match new_entries.iter().find(|e| e.header == old.header) {
+			None => errors.push(format!(
+				"HISTORY: released entry {} was deleted",
+				old.header
+			)),
+			Some(new) if trim_blank(&new.body) != trim_blank(&old.body) => {
+				errors.push(format!(
+					"HISTORY: released entry {} was modified",
+					new.header
+				));
+			}
+			Some(_) => {}
+		}
+	}
+
+	let first_released = new_entries
+		.iter()
+		.position(|e| old_entries.iter().any(|o| o.header == e.header));
+	if let Some(first) = first_released {
+		for entry in &new_entries[first..] {
+			if !old_entries.iter().any(|o| o.header == entry.header) {
+				errors.push(format!(
+					"HISTORY: new entry {} must be added above all released entries",
+					entry.header
+				));
+			}
+		}
+	}
+
+	Ok(errors)
+}
+
+fn trim_blank<'a>(lines: &'a [&'a str]) -> &'a [&'a str] {
+	let end = lines
+		.iter()
+		.rposition(|l| !l.trim().is_empty())
+		.map_or(0, |i| i + 1);
+	&lines[..end]
+}
+
+/// One `## [timestamp]` section of the CHANGELOG.
+pub struct Entry<'a> {
+	pub header: &'a str,
+	/// Lines after the header, up to the next entry.
+	pub body: Vec<&'a str>,
+	pub categories: Vec<Category<'a>>,
+}
+
+/// One `### Name` section inside an entry, with its bullet items.
+pub struct Category<'a> {
+	pub name: &'a str,
+	pub items: Vec<&'a str>,
+}
+
+pub fn parse_entries(content: &str) -> Vec<Entry<'_>> {
+	let mut entries: Vec<Entry> = Vec::new();
+
+	for line in content.lines() {
+		if line.starts_with("## [") {
+			entries.push(Entry {
+				header: line,
+				body: Vec::new(),
+				categories: Vec::new(),
+			});
+			continue;
+		}
+
+		let Some(entry) = entries.last_mut() else {
+			continue;
+		};
+		entry.body.push(line);
+
+		if let Some(name) = line.strip_prefix("### ") {
+			entry.categories.push(Category {
+				name,
+				items: Vec::new(),
+			});
+		} else if let Some(item) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* "))
+			&& let Some(category) = entry.categories.last_mut()
+		{
+			category.items.push(item);
+		}
+	}
+
+	entries
+}
+
+/// Items under `### {category}` in `new` that appear in no such section of `old`.
+pub fn added_items<'a>(old: &str, new: &'a str, category: &str) -> Vec<&'a str> {
+	let old_entries = parse_entries(old);
+	let old_items: Vec<&str> = old_entries
+		.iter()
+		.flat_map(|e| &e.categories)
+		.filter(|c| c.name == category)
+		.flat_map(|c| c.items.iter().copied())
+		.collect();
+
+	parse_entries(new)
+		.into_iter()
+		.flat_map(|e| e.categories)
+		.filter(|c| c.name == category)
+		.flat_map(|c| c.items)
+		.filter(|item| !old_items.contains(item))
+		.collect()
+}
+
+/// Extract `2026-02-10T16:16:38Z` from `## [2026-02-10T16:16:38Z]`.
+pub fn entry_timestamp(line: &str) -> Option<&str> {
+	let ts = line.strip_prefix("## [")?.strip_suffix(']')?;
+	has_timestamp_shape(ts).then_some(ts)
+}
+
+fn has_timestamp_shape(ts: &str) -> bool {
 	// YYYY-MM-DDTHH:MM:SSZ
+	let b = ts.as_bytes();
 	ts.len() == 20
-		&& ts.as_bytes()[4] == b'-'
-		&& ts.as_bytes()[7] == b'-'
-		&& ts.as_bytes()[10] == b'T'
-		&& ts.as_bytes()[13] == b':'
-		&& ts.as_bytes()[16] == b':'
-		&& ts.as_bytes()[19] == b'Z'
+		&& b[4] == b'-'
+		&& b[7] == b'-'
+		&& b[10] == b'T'
+		&& b[13] == b':'
+		&& b[16] == b':'
+		&& b[19] == b'Z'
+}
+
+/// A UTC timestamp as written in CHANGELOG entry headers.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Timestamp {
+	year: i64,
+	month: u32,
+	day: u32,
+	hour: u32,
+	minute: u32,
+	second: u32,
+}
+
+impl Timestamp {
+	/// Parse `YYYY-MM-DDTHH:MM:SSZ`, rejecting out-of-range fields.
+	pub fn parse(ts: &str) -> Option<Self> {
+		if !has_timestamp_shape(ts) {
+			return None;
+		}
+		let field = |range: std::ops::Range<usize>| -> Option<u32> {
+			let s = ts.get(range)?;
+			if !s.bytes().all(|b| b.is_ascii_digit()) {
+				return None;
+			}
+			s.parse().ok()
+		};
+
+		let parsed = Timestamp {
+			year: i64::from(field(0..4)?),
+			month: field(5..7)?,
+			day: field(8..10)?,
+			hour: field(11..13)?,
+			minute: field(14..16)?,
+			second: field(17..19)?,
+		};
+		let valid = (1..=12).contains(&parsed.month)
+			&& parsed.day >= 1
+			&& parsed.day <= days_in_month(parsed.year, parsed.month)
+			&& parsed.hour < 24
+			&& parsed.minute < 60
+			&& parsed.second < 60;
+		valid.then_some(parsed)
+	}
+
+	pub fn from_unix(secs: i64) -> Self {
+		let (year, month, day) = civil_from_days(secs.div_euclid(86_400));
+		let rem = secs.rem_euclid(86_400);
+		Timestamp {
+			year,
+			month,
+			day,
+			hour: (rem / 3600) as u32,
+			minute: (rem % 3600 / 60) as u32,
+			second: (rem % 60) as u32,
+		}
+	}
+
+	pub fn now() -> Self {
+		let secs = std::time::SystemTime::now()
+			.duration_since(std::time::UNIX_EPOCH)
+			.map(|d| d.as_secs() as i64)
+			.unwrap_or(0);
+		Self::from_unix(secs)
+	}
+
+	pub fn to_unix(self) -> i64 {
+		days_from_civil(self.year, self.month, self.day) * 86_400
+			+ i64::from(self.hour) * 3600
+			+ i64::from(self.minute) * 60
+			+ i64::from(self.second)
+	}
 }
 
-fn cat_order(category: &str) -> u8 {
+impl std::fmt::Display for Timestamp {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(
+			f,
+			"{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+			self.year, self.month, self.day, self.hour, self.minute, self.second
+		)
+	}
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+	match month {
+		1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+		4 | 6 | 9 | 11 => 30,
+		_ if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+		_ => 28,
+	}
+}
+
+// Howard Hinnant's days_from_civil / civil_from_days, proleptic Gregorian.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+	let y = if month <= 2 { year - 1 } else { year };
+	let era = y.div_euclid(400);
+	let yoe = y - era * 400;
+	let m = i64::from(month);
+	let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + i64::from(day) - 1;
+	let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+	era * 146_097 + doe - 719_468
+}
+
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+	let z = days + 719_468;
+	let era = z.div_euclid(146_097);
+	let doe = z - era * 146_097;
+	let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+	let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+	let mp = (5 * doy + 2) / 153;
+	let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+	let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+	let year = yoe + era * 400 + i64::from(month <= 2);
+	(year, month, day)
+}
+
+pub fn cat_order(category: &str) -> u8 {
 	match category {
 		"Breaking" => 1,
 		"Added" => 2,
@@ -81,3 +346,62 @@ fn cat_order(category: &str) -> u8 {
 		_ => 0,
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn timestamp_shape() {
+		assert!(entry_timestamp("## [2026-02-10T16:16:38Z]").is_some());
+		assert!(entry_timestamp("## [2026-02-10 16:16:38Z]").is_none());
+		assert!(entry_timestamp("## [2026-02-10T16:16:38]").is_none());
+	}
+
+	#[test]
+	fn timestamp_ranges() {
+		assert!(Timestamp::parse("2026-02-10T16:16:38Z").is_some());
+		assert!(Timestamp::parse("2024-02-29T00:00:00Z").is_some());
+		assert!(Timestamp::parse("2026-13-45T99:99:99Z").is_none());
+		assert!(Timestamp::parse("2026-02-29T00:00:00Z").is_none());
+		assert!(Timestamp::parse("2026-04-31T00:00:00Z").is_none());
+		assert!(Timestamp::parse("2026-01-01T24:00:00Z").is_none());
+		assert!(Timestamp::parse("2026-0a-01T00:00:00Z").is_none());
+	}
+
+	#[test]
+	fn unix_seconds() {
+		let ts = Timestamp::parse("2026-02-10T16:16:38Z");
+		assert_eq!(ts.map(Timestamp::to_unix), Some(1_770_740_198));
+		let epoch = Timestamp::parse("1970-01-01T00:00:00Z");
+		assert_eq!(epoch.map(Timestamp::to_unix), Some(0));
+		assert_eq!(
+			Timestamp::from_unix(1_770_740_198).to_string(),
+			"2026-02-10T16:16:38Z"
+		);
+		assert_eq!(
+			Timestamp::from_unix(951_782_400).to_string(),
+			"2000-02-29T00:00:00Z"
+		);
+	}
+
+	#[test]
+	fn added_breaking_items() {
+		let old = "## [2026-01-01T00:00:00Z]\n\n### Breaking\n\n- old\n";
+		let new = "## [2026-02-01T00:00:00Z]\n\n### Breaking\n\n- new\n\n### Added\n\n- other\n\n\
+		           ## [2026-01-01T00:00:00Z]\n\n### Breaking\n\n- old\n";
+		assert_eq!(added_items(old, new, "Breaking"), ["new"]);
+		assert!(added_items(old, new, "Removed").is_empty());
+	}
+
+	#[test]
+	fn entries_and_items() {
+		let content =
+			"# Changelog\n\n## [2026-02-10T16:16:38Z]\n\n### Added\n\n- one\n- two\n\n### Fixed\n";
+		let entries = parse_entries(content);
+		assert_eq!(entries.len(), 1);
+		assert_eq!(entries[0].categories.len(), 2);
+		assert_eq!(entries[0].categories[0].items, ["one", "two"]);
+		assert!(entries[0].categories[1].items.is_empty());
+	}
+}
@@ -0,0 +1,133 @@
+use std::path::Path;
+
+use super::collect_md_files;
+
+pub fn run(spec_dir: &Path) -> anyhow::Result<Vec<String>> {
+	let files = collect_md_files(spec_dir)?;
+	let mut errors = Vec::new();
+
+	for file in &files {
+		let content = std::fs::read_to_string(file)?;
+
+		for block in fenced_blocks(&content) {
+			let Some(lang) = block.lang else {
+				errors.push(format!(
+					"CODE: {}:{} — code block has no language tag (use `text` for plain output)",
+					file.display(),
+					block.line
+				));
+				continue;
+			};
+			if let Some((line, msg)) = syntax_error(lang, &block.body) {
+				errors.push(format!(
+					"CODE: {}:{} — invalid {lang}: {msg}",
+					file.display(),
+					block.line + line
+				));
+			}
+		}
+	}
+
+	Ok(errors)
+}
+
+struct Block<'a> {
+	/// Line of the opening fence.
+	line: usize,
+	lang: Option<&'a str>,
+	body: String,
+}
+
+fn fenced_blocks(content: &str) -> Vec<Block<'_>> {
+	let mut blocks = Vec::new();
+	let mut current: Option<Block> = None;
+
+	for (line_num, line) in content.lines().enumerate() {
+		if !line.starts_with("```") {
+			if let Some(block) = &mut current {
+				block.body.push_str(line);
+				block.body.push('\n');
+			}
+			continue;
+		}
+		match current.take() {
+			Some(block) => blocks.push(block),
+			None => {
+				// "```rust,ignore" and "``` toml {title=...}" carry extra info
+				let lang = line[3..]
+					.split(|c: char| c.is_whitespace() || c == ',' || c == '{')
+					.next()
+					.filter(|l| !l.is_empty());
+				current = Some(Block {
+					line: line_num + 1,
+					lang,
+					body: String::new(),
+				});
+			}
+		}
+	}
+
+	// an unclosed fence runs to the end of the file
+	blocks.extend(current);
+	blocks
+}
+
+/// First syntax error in a block body, as a 1-based line within the body.
+fn syntax_error(lang: &str, body: &str) -> Option<(usize, String)> {
+	match lang {
+		"json" => serde_json::from_str::<serde_json::Value>(body)
+			.err()
+			.map(|e| {
+				let msg = e.to_string();
+				let msg = msg
+					.split(" at line ")
+					.next()
+					.unwrap_or_default()
+					.to_string();
+				(e.line().max(1), msg)
+			}),
+		"toml" => body.parse::<toml::Table>().err().map(|e| {
+			let start = e.span().map_or(0, |s| s.start);
+			let line = body[..start.min(body.len())].matches('\n').count() + 1;
+			(line, e.message().trim().to_string())
+		}),
+		"yaml" | "yml" => yaml_rust2::YamlLoader::load_from_str(body)
+			.err()
+			.map(|e| (e.marker().line().max(1), e.info().to_string())),
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn fences() {
+		let content = "# T\n\n```\nplain\n```\n\n```toml {title=\"qwq.toml\"}\na = 1\n```\n";
+		let blocks = fenced_blocks(content);
+		assert_eq!(blocks.len(), 2);
+		assert_eq!((blocks[0].line, blocks[0].lang), (3, None));
+		assert_eq!((blocks[1].line, blocks[1].lang), (7, Some("toml")));
+		assert_eq!(blocks[1].body, "a = 1\n");
+	}
+
+	#[test]
+	fn syntax_errors() {
+		assert_eq!(syntax_error("json", "{\"a\": 1}\n"), None);
+		assert_eq!(
+			syntax_error("json", "{\n  \"a\": 1,\n}\n").map(|e| e.0),
+			Some(3)
+		);
+		assert_eq!(
+			syntax_error("toml", "a = 1\n[b]\nc = \n").map(|e| e.0),
+			Some(3)
+		);
+		assert_eq!(
+			syntax_error("yaml", "a: 1\nb: c: d\n").map(|e| e.0),
+			Some(2)
+		);
+		assert_eq!(syntax_error("yaml", "a: 1\nb:\n  - x\n"), None);
+		assert_eq!(syntax_error("rust", "fn {"), None);
+	}
+}
@@ -0,0 +1,314 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use anyhow::Context;
+use ureq::Agent;
+
+use super::glob_match;
+use crate::config;
+
+/// `[check.links]` settings for `qwq check links --external`.
+struct Settings {
+	concurrency: usize,
+	timeout: Duration,
+	retries: usize,
+	/// Minimum gap between two requests to the same host.
+	host_interval: Duration,
+	cache_ttl: Duration,
+	cache: PathBuf,
+	/// URL globs never requested, e.g. `https://*.internal/**`.
+	ignore: Vec<String>,
+}
+
+impl Settings {
+	fn load(repo_root: &Path) -> anyhow::Result<Self> {
+		let table = config::load_table("check.links")?;
+		let num = |key: &str, default: usize| {
+			table
+				.as_ref()
+				.and_then(|t| t.get_usize(key))
+				.unwrap_or(default)
+		};
+		let cache = table
+			.as_ref()
+			.and_then(|t| t.get_str("cache"))
+			.unwrap_or_else(|| "target/qwq-link-cache.tsv".to_string());
+
+		Ok(Settings {
+			concurrency: num("concurrency", 8).max(1),
+			timeout: Duration::from_secs(num("timeout_secs", 10) as u64),
+			retries: num("retries", 2),
+			host_interval: Duration::from_millis(num("host_interval_ms", 250) as u64),
+			cache_ttl: Duration::from_secs(num("cache_ttl_hours", 24) as u64 * 3600),
+			cache: repo_root.join(cache),
+			ignore: table
+				.as_ref()
+				.and_then(|t| t.get_list("ignore"))
+				.unwrap_or_default(),
+		})
+	}
+}
+
+/// Check `(url, location)` pairs over the network. URLs that answered
+/// successfully within the cache TTL are not requested again; failures are
+/// never cached so a fixed link is picked up on the next run.
+pub fn run(links: &[(String, String)], repo_root: &Path) -> anyhow::Result<Vec<String>> {
+	let settings = Settings::load(repo_root)?;
+	let now = unix_now();
+	let mut cache = load_cache(&settings.cache)?;
+	cache.retain(|_, checked| now.saturating_sub(*checked) < settings.cache_ttl.as_secs());
+
+	let mut pending: Vec<&str> = links
+		.iter()
+		.map(|(url, _)| url.split('#').next().unwrap_or(url))
+		.filter(|url| !settings.ignore.iter().any(|g| glob_match(g, url)))
+		.filter(|url| !cache.contains_key(*url))
+		.collect();
+	pending.sort_unstable();
+	pending.dedup();
+
+	if !pending.is_empty() {
+		eprintln!("checking {} external link(s)...", pending.len());
+	}
+	let results = check_all(&pending, &settings);
+
+	let mut errors = Vec::new();
+	let mut failed: HashMap<&str, &str> = HashMap::new();
+	for (url, result) in &results {
+		match result {
+			Ok(()) => {
+				cache.insert(url.to_string(), now);
+			}
+			Err(reason) => {
+				failed.insert(url, reason);
+			}
+		}
+	}
+	for (url, location) in links {
+		let bare = url.split('#').next().unwrap_or(url);
+		if let Some(reason) = failed.get(bare) {
+			errors.push(format!("BROKEN URL: {location} -> {url} ({reason})"));
+		}
+	}
+
+	save_cache(&settings.cache, &cache)?;
+	Ok(errors)
+}
+
+fn check_all<'a>(urls: &[&'a str], settings: &Settings) -> Vec<(&'a str, Result<(), String>)> {
+	let agent: Agent = Agent::config_builder()
+		.timeout_global(Some(settings.timeout))
+		.http_status_as_error(false)
+		.user_agent(concat!("qwq/", env!("CARGO_PKG_VERSION")))
+		.build()
+		.into();
+	let limiter = HostLimiter::new(settings.host_interval);
+	let queue = Mutex::new(urls.iter());
+	let results = Mutex::new(Vec::new());
+
+	std::thread::scope(|s| {
+		for _ in 0..settings.concurrency.min(urls.len()) {
+			s.spawn(|| {
+				loop {
+					let next = queue.lock().unwrap_or_else(|e| e.into_inner()).next();
+					let Some(&url) = next else {
+						break;
+					};
+					let result = check_url(&agent, url, settings.retries, &limiter);
+					results
+						.lock()
+						.unwrap_or_else(|e| e.into_inner())
+						.push((url, result));
+				}
+			});
+		}
+	});
+
+	let mut results = results.into_inner().unwrap_or_else(|e| e.into_inner());
+	results.sort_by_key(|(url, _)| *url);
+	results
+}
+
+/// HEAD the URL, falling back to GET for servers that refuse HEAD. Rate
+/// limiting (429), server errors and transport failures are retried with a
+/// growing delay; other 4xx answers fail at once.
+fn check_url(
+	agent: &Agent,
+	url: &str,
+	retries: usize,
+	limiter: &HostLimiter,
+) -> Result<(), String> {
+	let host = host_of(url);
+	let mut last_error = String::new();
+
+	for attempt in 0..=retries {
+		if attempt > 0 {
+			std::thread::sleep(Duration::from_millis(500 * attempt as u64));
+		}
+
+		limiter.wait(host);
+		let mut status = agent.head(url).call().map(|r| r.status().as_u16());
+		if matches!(status, Ok(403 | 405 | 501)) {
+			limiter.wait(host);
+			status = agent.get(url).call().map(|r| r.status().as_u16());
+		}
+
+		match status {
+			Ok(code) if code < 400 => return Ok(()),
+			Ok(code @ (429 | 500..)) => last_error = format!("HTTP {code}"),
+			Ok(code) => return Err(format!("HTTP {code}")),
+			Err(e) => last_error = e.to_string(),
+		}
+	}
+
+	Err(last_error)
+}
+
+fn host_of(url: &str) -> &str {
+	let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+	rest.split(['/', '?', '#']).next().unwrap_or(rest)
+}
+
+/// Spaces out requests to the same host across all worker threads.
+struct HostLimiter {
+	interval: Duration,
+	next_slot: Mutex<HashMap<String, Instant>>,
+}
+
+impl HostLimiter {
+	fn new(interval: Duration) -> Self {
+		Self {
+			interval,
+			next_slot: Mutex::new(HashMap::new()),
+		}
+	}
+
+	fn wait(&self, host: &str) {
+		let now = Instant::now();
+		let slot = {
+			let mut next = self.next_slot.lock().unwrap_or_else(|e| e.into_inner());
+			let slot = next.get(host).copied().unwrap_or(now).max(now);
+			next.insert(host.to_string(), slot + self.interval);
+			slot
+		};
+		std::thread::sleep(slot - now);
+	}
+}
+
+fn unix_now() -> u64 {
+	SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map_or(0, |d| d.as_secs())
+}
+
+/// `url<TAB>unix seconds` per line, one line per URL that last answered OK.
+fn load_cache(path: &Path) -> anyhow::Result<HashMap<String, u64>> {
+	if !path.exists() {
+		return Ok(HashMap::new());
+	}
+	let content =
+		std::fs::read_to_string(path).with_context(|| format!("cannot read {}", path.display()))?;
+	Ok(
+		content
+			.lines()
+			.filter_map(|line| {
+				let (url, checked) = line.split_once('\t')?;
+				Some((url.to_string(), checked.parse().ok()?))
+			})
+			.collect(),
+	)
+}
+
+fn save_cache(path: &Path, cache: &HashMap<String, u64>) -> anyhow::Result<()> {
+	let mut entries: Vec<_> = cache.iter().collect();
+	entries.sort();
+	let content: String = entries
+		.iter()
+		.map(|(url, checked)| format!("{url}\t{checked}\n"))
+		.collect();
+
+	if let Some(dir) = path.parent() {
+		std::fs::create_dir_all(dir).with_context(|| format!("cannot create {}", dir.display()))?;
+	}
+	std::fs::write(path, content).with_context(|| format!("cannot write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+	use std::io::{BufRead, BufReader, Write};
+	use std::net::TcpListener;
+	use std::sync::atomic::{AtomicUsize, Ordering};
+
+	use super::*;
+
+	/// A stand-in HTTP server answering by method and path; returns its base URL.
+	fn serve() -> anyhow::Result<String> {
+		let listener = TcpListener::bind("127.0.0.1:0")?;
+		let base = format!("http://{}", listener.local_addr()?);
+		let flaky = AtomicUsize::new(0);
+
+		std::thread::spawn(move || {
+			for mut stream in listener.incoming().flatten() {
+				let mut reader = BufReader::new(&stream);
+				let mut request = String::new();
+				let _ = reader.read_line(&mut request);
+				let mut header = String::new();
+				while reader.read_line(&mut header).is_ok_and(|n| n > 2) {
+					header.clear();
+				}
+
+				let mut parts = request.split_whitespace();
+				let method = parts.next().unwrap_or_default();
+				let status = match (method, parts.next().unwrap_or_default()) {
+					(_, "/ok") => 200,
+					("HEAD", "/no-head") => 405,
+					("GET", "/no-head") => 200,
+					(_, "/flaky") if flaky.fetch_add(1, Ordering::SeqCst) == 0 => 503,
+					(_, "/flaky") => 200,
+					_ => 404,
+				};
+				let _ = write!(
+					stream,
+					"HTTP/1.1 {status} Status\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+				);
+			}
+		});
+		Ok(base)
+	}
+
+	#[test]
+	fn stand_in_server() -> anyhow::Result<()> {
+		let base = serve()?;
+		let agent: Agent = Agent::config_builder()
+			.timeout_global(Some(Duration::from_secs(5)))
+			.http_status_as_error(false)
+			.build()
+			.into();
+		let limiter = HostLimiter::new(Duration::ZERO);
+		let check = |path: &str| check_url(&agent, &format!("{base}{path}"), 1, &limiter);
+
+		assert_eq!(check("/ok"), Ok(()));
+		assert_eq!(check("/no-head"), Ok(()));
+		assert_eq!(check("/flaky"), Ok(()));
+		assert_eq!(check("/missing"), Err("HTTP 404".to_string()));
+		Ok(())
+	}
+
+	#[test]
+	fn host_rate_limit() {
+		let limiter = HostLimiter::new(Duration::from_millis(50));
+		let start = Instant::now();
+		limiter.wait("example.com");
+		limiter.wait("example.org");
+		limiter.wait("example.com");
+		assert!(start.elapsed() >= Duration::from_millis(50));
+	}
+
+	#[test]
+	fn hosts() {
+		assert_eq!(host_of("https://example.com/a?b"), "example.com");
+		assert_eq!(host_of("http://127.0.0.1:8080"), "127.0.0.1:8080");
+	}
+}
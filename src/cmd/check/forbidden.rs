@@ -1,36 +1,300 @@
 use std::path::Path;
 
+use anyhow::{Context, bail};
+use regex::Regex;
+
 use super::collect_md_files;
+use super::terminology::strip_inline_code;
+use super::unicode::{self, Script};
+use crate::config;
 
-pub fn run(spec_dir: &Path) -> anyhow::Result<Vec<String>> {
+pub fn run(spec_dir: &Path, repo_root: &Path) -> anyhow::Result<Vec<String>> {
 	let files = collect_md_files(spec_dir)?;
+	let rules = load_rules(&config::load_tables()?)?;
 	let mut errors = Vec::new();
 
 	for file in &files {
 		let content = std::fs::read_to_string(file)?;
-		let mut in_code_block = false;
+		let rel = super::rel_path(file, repo_root);
+		let active: Vec<&Rule> = rules.iter().filter(|r| r.applies_to(&rel)).collect();
 
-		for (line_num, line) in content.lines().enumerate() {
-			in_code_block = super::is_in_code_block(line, in_code_block);
-			if in_code_block || line.starts_with("```") {
-				continue;
+		for (line, kind, detail) in scan(&content, &active) {
+			match detail {
+				Some(detail) => errors.push(format!("{kind}: {}:{line} — {detail}", file.display())),
+				None => errors.push(format!("{kind}: {}:{line}", file.display())),
 			}
+		}
+	}
+
+	Ok(errors)
+}
+
+/// Line number, kind and detail of every finding in one file.
+fn scan(content: &str, rules: &[&Rule]) -> Vec<(usize, &'static str, Option<String>)> {
+	let mut findings = Vec::new();
+	let mut in_code_block = false;
+
+	for (line_num, line) in content.lines().enumerate() {
+		// hidden characters are dangerous in code blocks too
+		let line = if line_num == 0 {
+			line.strip_prefix('\u{FEFF}').unwrap_or(line)
+		} else {
+			line
+		};
+		for (kind, detail) in hidden_chars(line) {
+			findings.push((line_num + 1, kind, Some(detail)));
+		}
 
-			if contains_emoji(line) {
-				errors.push(format!("EMOJI: {}:{}", file.display(), line_num + 1));
+		in_code_block = super::is_in_code_block(line, in_code_block);
+		if in_code_block || line.starts_with("```") {
+			continue;
+		}
+
+		if contains_emoji(line) {
+			findings.push((line_num + 1, "EMOJI", None));
+		}
+
+		if rules.is_empty() {
+			continue;
+		}
+		let prose = strip_inline_code(line);
+		for rule in rules {
+			if rule.is_match(&prose) {
+				findings.push((line_num + 1, "FORBIDDEN", Some(rule.message.clone())));
 			}
 		}
 	}
 
-	Ok(errors)
+	findings
+}
+
+/// A `[[check.forbidden]]` entry from qwq.toml.
+struct Rule {
+	matcher: Matcher,
+	message: String,
+	/// Repo-relative globs; empty means every checked file.
+	paths: Vec<String>,
 }
 
+enum Matcher {
+	Literal(String),
+	Regex(Regex),
+}
+
+impl Rule {
+	fn applies_to(&self, rel: &str) -> bool {
+		self.paths.is_empty() || self.paths.iter().any(|p| super::glob_match(p, rel))
+	}
+
+	fn is_match(&self, text: &str) -> bool {
+		match &self.matcher {
+			Matcher::Literal(s) => text.contains(s.as_str()),
+			Matcher::Regex(re) => re.is_match(text),
+		}
+	}
+}
+
+fn load_rules(tables: &[config::Table]) -> anyhow::Result<Vec<Rule>> {
+	let mut rules = Vec::new();
+
+	for table in tables {
+		if table.name != "check.forbidden" {
+			continue;
+		}
+
+		let (matcher, shown) = match (table.get_str("pattern"), table.get_str("regex")) {
+			(Some(pattern), None) => (Matcher::Literal(pattern.clone()), pattern),
+			(None, Some(regex)) => {
+				let re = Regex::new(&regex)
+					.with_context(|| format!("invalid regex in [[check.forbidden]]: {regex}"))?;
+				(Matcher::Regex(re), regex)
+			}
+			_ => bail!("each [[check.forbidden]] rule needs exactly one of `pattern` or `regex`"),
+		};
+
+		rules.push(Rule {
+			matcher,
+			message: table
+				.get_str("message")
+				.unwrap_or_else(|| format!("forbidden pattern '{shown}'")),
+			paths: table.get_list("paths").unwrap_or_default(),
+		});
+	}
+
+	Ok(rules)
+}
+
+/// Emoji are code points with `Emoji_Presentation`, keycap sequences, and
+/// other `Emoji` code points explicitly given emoji presentation with U+FE0F.
+/// Text-default symbols such as ↔ ✔ ♥ © and ASCII are ordinary typography.
 fn contains_emoji(line: &str) -> bool {
-	line.chars().any(|c| {
-		let cp = c as u32;
-		(0x1F000..=0x1FFFF).contains(&cp)
-			|| (0x2600..=0x27BF).contains(&cp)
-			|| (0xFE00..=0xFE0F).contains(&cp)
-			|| cp == 0x200D
+	let chars: Vec<char> = line.chars().collect();
+	chars.iter().enumerate().any(|(i, &c)| {
+		let next = chars.get(i + 1).copied();
+		match c {
+			'0'..='9' | '#' | '*' => {
+				next == Some('\u{20E3}')
+					|| (next == Some('\u{FE0F}') && chars.get(i + 2) == Some(&'\u{20E3}'))
+			}
+			_ => unicode::is_emoji_presentation(c) || (unicode::is_emoji(c) && next == Some('\u{FE0F}')),
+		}
 	})
 }
+
+/// Invisible, bidi-control, no-break-space and mixed-script findings for a line.
+/// Joiners, variation selectors and tags that belong to an emoji sequence are
+/// left to the emoji check.
+fn hidden_chars(line: &str) -> Vec<(&'static str, String)> {
+	let chars: Vec<char> = line.chars().collect();
+	let mut findings = Vec::new();
+	let mut in_tag_sequence = false;
+
+	for (i, &c) in chars.iter().enumerate() {
+		let prev = i.checked_sub(1).map(|p| chars[p]);
+		let next = chars.get(i + 1).copied();
+		let emoji_like = |c: Option<char>| {
+			c.is_some_and(|c| unicode::is_emoji(c) || c == '\u{FE0F}' || c == '\u{20E3}')
+		};
+
+		in_tag_sequence = match c {
+			'\u{E0020}'..='\u{E007F}' => in_tag_sequence || prev == Some('\u{1F3F4}'),
+			_ => false,
+		};
+		let part_of_emoji = match c {
+			'\u{200D}' => emoji_like(prev) && next.is_some_and(unicode::is_emoji),
+			'\u{FE0E}' | '\u{FE0F}' => prev.is_some_and(unicode::is_emoji),
+			'\u{E0020}'..='\u{E007F}' => in_tag_sequence,
+			_ => false,
+		};
+		if part_of_emoji {
+			continue;
+		}
+
+		let code = format!("U+{:04X}", c as u32);
+		if let Some(name) = unicode::bidi_name(c) {
+			findings.push(("BIDI", format!("{code} {name}")));
+		} else if let Some(name) = unicode::invisible_name(c) {
+			findings.push(("INVISIBLE", format!("{code} {name}")));
+		} else if let Some(name) = unicode::nbsp_name(c) {
+			findings.push(("NBSP", format!("{code} {name} (use a regular space)")));
+		}
+	}
+
+	for word in line.split(|c: char| !c.is_alphanumeric()) {
+		let scripts: Vec<Script> = word
+			.chars()
+			.filter_map(unicode::confusable_script)
+			.collect();
+		let mixed = scripts.contains(&Script::Latin)
+			&& (scripts.contains(&Script::Cyrillic) || scripts.contains(&Script::Greek));
+		if mixed {
+			findings.push((
+				"HOMOGLYPH",
+				format!("'{word}' mixes Latin with Cyrillic or Greek letters"),
+			));
+		}
+	}
+
+	findings
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn rules(toml: &str) -> anyhow::Result<Vec<Rule>> {
+		load_rules(&config::parse_tables(toml)?)
+	}
+
+	fn forbidden(content: &str, rules: &[Rule]) -> Vec<(usize, Option<String>)> {
+		let active: Vec<&Rule> = rules.iter().collect();
+		scan(content, &active)
+			.into_iter()
+			.filter(|(_, kind, _)| *kind == "FORBIDDEN")
+			.map(|(line, _, detail)| (line, detail))
+			.collect()
+	}
+
+	#[test]
+	fn literal_and_regex_rules() -> anyhow::Result<()> {
+		let rules = rules(
+			r#"
+[[check.forbidden]]
+pattern = "TODO"
+message = "resolve TODOs before review"
+
+[[check.forbidden]]
+regex = '\bcolour\b'
+"#,
+		)?;
+		assert_eq!(
+			forbidden("a TODO here\nthe colour red\ncolours are fine\n", &rules),
+			[
+				(1, Some("resolve TODOs before review".to_string())),
+				(2, Some("forbidden pattern '\\bcolour\\b'".to_string())),
+			]
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn code_is_exempt() -> anyhow::Result<()> {
+		let rules = rules("[[check.forbidden]]\npattern = \"TODO\"\n")?;
+		let content = "`TODO` in code\n```\nTODO in a block\n```\nTODO in prose\n";
+		assert_eq!(
+			forbidden(content, &rules),
+			[(5, Some("forbidden pattern 'TODO'".to_string()))]
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn path_filter() -> anyhow::Result<()> {
+		let rules = rules("[[check.forbidden]]\npattern = \"x\"\npaths = [\"spec/api/**\"]\n")?;
+		assert!(rules[0].applies_to("spec/api/v1/calls.md"));
+		assert!(!rules[0].applies_to("spec/guide.md"));
+		Ok(())
+	}
+
+	#[test]
+	fn invalid_rules() {
+		let err = |toml: &str| rules(toml).is_err();
+		assert!(err("[[check.forbidden]]\npattern = \"a\"\nregex = \"b\"\n"));
+		assert!(err("[[check.forbidden]]\nmessage = \"m\"\n"));
+		assert!(err("[[check.forbidden]]\nregex = \"(\"\n"));
+	}
+
+	#[test]
+	fn emoji() {
+		assert!(contains_emoji("star \u{2B50}"));
+		assert!(contains_emoji("up \u{2B06}\u{FE0F}"));
+		assert!(contains_emoji("heart \u{2764}\u{FE0F}"));
+		assert!(contains_emoji("keycap 1\u{FE0F}\u{20E3}"));
+		assert!(contains_emoji("flag \u{1F1E8}\u{1F1F3}"));
+		assert!(contains_emoji("\u{00A9}\u{FE0F}"));
+	}
+
+	#[test]
+	fn not_emoji() {
+		assert!(!contains_emoji("check \u{2713} star \u{2605} box \u{2610}"));
+		assert!(!contains_emoji("a \u{2194} b \u{2714} done"));
+		assert!(!contains_emoji(
+			"\u{2196} \u{203C} \u{2139} \u{25B6} \u{2600} \u{2665} \u{2716} \u{2764} \u{2B06} \u{3030}"
+		));
+		assert!(!contains_emoji("(c) \u{00A9} 2026, #1 and 2*3"));
+		assert!(!contains_emoji("中文，标点。"));
+	}
+
+	#[test]
+	fn hidden() {
+		assert_eq!(hidden_chars("a\u{200B}b").len(), 1);
+		assert_eq!(hidden_chars("if x \u{202E}y").len(), 1);
+		assert_eq!(hidden_chars("1\u{00A0}km").len(), 1);
+		assert_eq!(hidden_chars("p\u{0430}ssword").len(), 1);
+		assert!(hidden_chars("family \u{1F468}\u{200D}\u{1F469}\u{FE0F}").is_empty());
+		assert!(hidden_chars("Tired \u{1FAE9}\u{FE0F} face").is_empty());
+		assert!(hidden_chars("plain ascii, Ελληνικά, русский").is_empty());
+		assert!(hidden_chars("10μs per Δt, 5Ω").is_empty());
+		assert_eq!(hidden_chars("hell\u{03BF} world").len(), 1);
+	}
+}
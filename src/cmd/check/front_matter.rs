@@ -0,0 +1,289 @@
+use std::path::Path;
+
+use yaml_rust2::{Yaml, YamlLoader};
+
+use super::{collect_md_files, glob_match, rel_path};
+use crate::config;
+
+/// Key a deprecated page uses to point at the page that replaces it.
+const REPLACED_BY: &str = "replaced_by";
+
+/// Lines taken by a leading `---` YAML block, delimiters included; 0 when
+/// the file has none. Prose checks skip these lines.
+pub fn line_count(content: &str) -> usize {
+	match split(content) {
+		Some(Ok((_, lines))) => lines,
+		_ => 0,
+	}
+}
+
+/// The YAML text of the front matter and the lines it takes, or an error when
+/// the opening `---` is never closed.
+fn split(content: &str) -> Option<Result<(&str, usize), ()>> {
+	let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+	let mut lines = content.split_inclusive('\n');
+	if lines.next()?.trim_end() != "---" {
+		return None;
+	}
+
+	let start = content.find('\n')? + 1;
+	let mut offset = start;
+	for (i, line) in lines.enumerate() {
+		if matches!(line.trim_end(), "---" | "...") {
+			return Some(Ok((&content[start..offset], i + 2)));
+		}
+		offset += line.len();
+	}
+	Some(Err(()))
+}
+
+/// `[check.front_matter]` and `[check.front_matter.values]`.
+struct Schema {
+	required: Vec<String>,
+	/// Empty means any key is allowed.
+	allowed: Vec<String>,
+	/// Key and the values it may take.
+	values: Vec<(String, Vec<String>)>,
+	/// Repo-relative globs of pages that need no front matter.
+	exclude: Vec<String>,
+}
+
+impl Schema {
+	fn load() -> anyhow::Result<Self> {
+		let tables = config::load_tables()?;
+		let find = |name: &str| tables.iter().find(|t| t.name == name);
+
+		let main = find("check.front_matter");
+		let list = |key: &str| main.and_then(|t| t.get_list(key)).unwrap_or_default();
+		let values = find("check.front_matter.values")
+			.map(|t| {
+				t.keys()
+					.filter_map(|k| Some((k.to_string(), t.get_list(k)?)))
+					.collect()
+			})
+			.unwrap_or_default();
+
+		Ok(Schema {
+			required: list("required"),
+			allowed: list("allowed"),
+			values,
+			exclude: list("exclude"),
+		})
+	}
+}
+
+pub fn run(spec_dir: &Path, repo_root: &Path) -> anyhow::Result<Vec<String>> {
+	let files = collect_md_files(spec_dir)?;
+	let schema = Schema::load()?;
+	let mut errors = Vec::new();
+
+	for file in &files {
+		let rel = rel_path(file, repo_root);
+		let name = file.file_name().unwrap_or_default().to_string_lossy();
+		if name == "CHANGELOG.md" || schema.exclude.iter().any(|g| glob_match(g, &rel)) {
+			continue;
+		}
+		let content = std::fs::read_to_string(file)?;
+
+		let yaml = match split(&content) {
+			None if schema.required.is_empty() => continue,
+			None => {
+				errors.push(format!(
+					"FRONT-MATTER: {} — missing front matter (required: {})",
+					file.display(),
+					schema.required.join(", ")
+				));
+				continue;
+			}
+			Some(Err(())) => {
+				errors.push(format!(
+					"FRONT-MATTER: {}:1 — front matter is not closed with ---",
+					file.display()
+				));
+				continue;
+			}
+			Some(Ok((yaml, _))) => yaml,
+		};
+
+		let base = file.parent().unwrap_or(spec_dir);
+		for (line, msg) in validate(yaml, &schema, base) {
+			errors.push(format!("FRONT-MATTER: {}:{line} — {msg}", file.display()));
+		}
+	}
+
+	Ok(errors)
+}
+
+/// Findings for one front matter block, with line numbers in the page.
+fn validate(yaml: &str, schema: &Schema, base: &Path) -> Vec<(usize, String)> {
+	let docs = match YamlLoader::load_from_str(yaml) {
+		Ok(docs) => docs,
+		Err(e) => return vec![(e.marker().line() + 1, format!("invalid YAML: {}", e.info()))],
+	};
+	let empty = yaml_rust2::yaml::Hash::new();
+	let fields = match docs.first() {
+		None => &empty,
+		Some(Yaml::Hash(fields)) => fields,
+		Some(_) => return vec![(2, "front matter must be a mapping of keys".to_string())],
+	};
+	// the opening --- is line 1
+	let line_of = |key: &str| key_line(yaml, key).map_or(1, |l| l + 1);
+	let mut findings = Vec::new();
+
+	for key in &schema.required {
+		if !fields.contains_key(&Yaml::String(key.clone())) {
+			findings.push((1, format!("missing required key '{key}'")));
+		}
+	}
+
+	for key in fields.keys() {
+		let Some(key) = key.as_str() else {
+			findings.push((1, "keys must be strings".to_string()));
+			continue;
+		};
+		if !schema.allowed.is_empty() && !schema.allowed.iter().any(|a| a == key) {
+			findings.push((
+				line_of(key),
+				format!(
+					"unknown key '{key}' (allowed: {})",
+					schema.allowed.join(", ")
+				),
+			));
+		}
+	}
+
+	for (key, allowed) in &schema.values {
+		let Some(value) = fields.get(&Yaml::String(key.clone())) else {
+			continue;
+		};
+		match scalar(value) {
+			Some(v) if allowed.contains(&v) => {}
+			Some(v) => findings.push((
+				line_of(key),
+				format!("{key} '{v}' is not one of: {}", allowed.join(", ")),
+			)),
+			None => findings.push((line_of(key), format!("{key} must be a single value"))),
+		}
+	}
+
+	let status = fields
+		.get(&Yaml::String("status".to_string()))
+		.and_then(scalar);
+	if status.as_deref() == Some("deprecated") {
+		match fields
+			.get(&Yaml::String(REPLACED_BY.to_string()))
+			.and_then(scalar)
+		{
+			None => findings.push((
+				line_of("status"),
+				format!("deprecated page must link to its replacement with '{REPLACED_BY}'"),
+			)),
+			Some(target)
+				if !base
+					.join(target.split('#').next().unwrap_or_default())
+					.is_file() =>
+			{
+				findings.push((
+					line_of(REPLACED_BY),
+					format!("{REPLACED_BY} '{target}' does not exist"),
+				));
+			}
+			Some(_) => {}
+		}
+	}
+
+	findings
+}
+
+fn scalar(value: &Yaml) -> Option<String> {
+	match value {
+		Yaml::String(s) | Yaml::Real(s) => Some(s.clone()),
+		Yaml::Integer(i) => Some(i.to_string()),
+		Yaml::Boolean(b) => Some(b.to_string()),
+		_ => None,
+	}
+}
+
+/// 1-based line of a top-level `key:` within the YAML text.
+fn key_line(yaml: &str, key: &str) -> Option<usize> {
+	yaml
+		.lines()
+		.position(|l| {
+			l.strip_prefix(key)
+				.is_some_and(|rest| rest.trim_start().starts_with(':'))
+		})
+		.map(|i| i + 1)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn schema() -> Schema {
+		Schema {
+			required: vec!["status".to_string(), "owner".to_string()],
+			allowed: vec![
+				"status".to_string(),
+				"owner".to_string(),
+				"since".to_string(),
+				REPLACED_BY.to_string(),
+			],
+			values: vec![(
+				"status".to_string(),
+				vec![
+					"draft".to_string(),
+					"stable".to_string(),
+					"deprecated".to_string(),
+				],
+			)],
+			exclude: Vec::new(),
+		}
+	}
+
+	#[test]
+	fn splitting() {
+		let content = "---\nstatus: draft\n---\n# Title\n";
+		assert_eq!(split(content), Some(Ok(("status: draft\n", 3))));
+		assert_eq!(line_count(content), 3);
+		assert_eq!(line_count("# Title\n---\n"), 0);
+		assert_eq!(split("---\nstatus: draft\n"), Some(Err(())));
+	}
+
+	#[test]
+	fn schema_violations() {
+		let base = Path::new("/nonexistent");
+		assert!(validate("status: stable\nowner: net\nsince: 1.2\n", &schema(), base).is_empty());
+
+		let findings = validate("status: wip\ncolor: red\n", &schema(), base);
+		assert_eq!(
+			findings,
+			[
+				(1, "missing required key 'owner'".to_string()),
+				(
+					3,
+					"unknown key 'color' (allowed: status, owner, since, replaced_by)".to_string()
+				),
+				(
+					2,
+					"status 'wip' is not one of: draft, stable, deprecated".to_string()
+				),
+			]
+		);
+	}
+
+	#[test]
+	fn deprecated_needs_replacement() {
+		let base = Path::new("/nonexistent");
+		let findings = validate("status: deprecated\nowner: net\n", &schema(), base);
+		assert_eq!(findings.len(), 1);
+		let findings = validate(
+			"status: deprecated\nowner: net\nreplaced_by: new.md\n",
+			&schema(),
+			base,
+		);
+		assert_eq!(
+			findings,
+			[(4, "replaced_by 'new.md' does not exist".to_string())]
+		);
+	}
+}
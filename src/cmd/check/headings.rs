@@ -0,0 +1,194 @@
+use std::path::Path;
+
+use anyhow::bail;
+
+use super::collect_md_files;
+use super::terminology::strip_inline_code;
+use crate::config;
+
+pub fn run(spec_dir: &Path) -> anyhow::Result<Vec<String>> {
+	let files = collect_md_files(spec_dir)?;
+	let case = load_case()?;
+	let mut errors = Vec::new();
+
+	for file in &files {
+		let content = std::fs::read_to_string(file)?;
+		let is_changelog = file.file_name().is_some_and(|n| n == "CHANGELOG.md");
+		let mut in_code_block = false;
+		let mut h1_count = 0;
+		let mut last_level = 1;
+		let mut seen: Vec<(usize, String)> = Vec::new();
+
+		for (line_num, line) in content
+			.lines()
+			.enumerate()
+			.skip(super::front_matter::line_count(&content))
+		{
+			in_code_block = super::is_in_code_block(line, in_code_block);
+			if in_code_block || line.starts_with("```") {
+				continue;
+			}
+			let Some((level, text)) = parse_heading(line) else {
+				continue;
+			};
+			let mut report = |msg: String| {
+				errors.push(format!(
+					"HEADING: {}:{} — {msg}",
+					file.display(),
+					line_num + 1
+				));
+			};
+
+			if level == 1 {
+				h1_count += 1;
+				if h1_count > 1 {
+					report("more than one H1 in file".to_string());
+				}
+			}
+			if level > last_level + 1 {
+				report(format!("skipped heading level (H{last_level} -> H{level})"));
+			}
+			last_level = level;
+
+			if !is_changelog {
+				let key = text.to_lowercase();
+				if seen.iter().any(|(l, t)| *l == level && *t == key) {
+					report(format!("duplicate H{level} \"{text}\" (ambiguous anchor)"));
+				}
+				seen.push((level, key));
+			}
+
+			if let Some(p) = text
+				.chars()
+				.last()
+				.filter(|c| ".,;:!。，；：！".contains(*c))
+			{
+				report(format!("trailing punctuation '{p}' in \"{text}\""));
+			}
+
+			if !case.accepts(text) {
+				report(format!("\"{text}\" is not in {} case", case.name()));
+			}
+		}
+
+		if h1_count == 0 && !content.trim().is_empty() {
+			errors.push(format!("HEADING: {} — missing H1", file.display()));
+		}
+	}
+
+	Ok(errors)
+}
+
+/// ATX heading level and text, without the optional closing `#` sequence.
+fn parse_heading(line: &str) -> Option<(usize, &str)> {
+	let level = line.chars().take_while(|&c| c == '#').count();
+	if !(1..=6).contains(&level) {
+		return None;
+	}
+	let rest = &line[level..];
+	if !rest.is_empty() && !rest.starts_with(' ') {
+		return None;
+	}
+	let text = rest.trim();
+	let text = match text.trim_end_matches('#') {
+		stripped if stripped.is_empty() || stripped.ends_with(' ') => stripped.trim_end(),
+		_ => text,
+	};
+	Some((level, text))
+}
+
+#[derive(Clone, Copy)]
+enum Case {
+	Any,
+	Sentence,
+	Title,
+}
+
+/// Words kept lowercase inside a title-case heading.
+const MINOR_WORDS: &[&str] = &[
+	"a", "an", "and", "as", "at", "but", "by", "for", "in", "nor", "of", "on", "or", "so", "the",
+	"to", "up", "via", "vs", "yet",
+];
+
+impl Case {
+	fn name(self) -> &'static str {
+		match self {
+			Case::Any => "any",
+			Case::Sentence => "sentence",
+			Case::Title => "title",
+		}
+	}
+
+	fn accepts(self, text: &str) -> bool {
+		let prose = strip_inline_code(text);
+		let words: Vec<&str> = prose
+			.split_whitespace()
+			.filter(|w| w.starts_with(|c: char| c.is_alphabetic()))
+			.collect();
+		let last = words.len().saturating_sub(1);
+
+		words.iter().enumerate().all(|(i, word)| {
+			// acronyms, identifiers and CJK carry their own casing
+			let lower_start = word.starts_with(|c: char| c.is_lowercase());
+			let upper_start = word.starts_with(|c: char| c.is_uppercase());
+			let plain = word.chars().skip(1).all(|c| !c.is_uppercase());
+			if !plain || (!lower_start && !upper_start) {
+				return true;
+			}
+			match self {
+				Case::Any => true,
+				Case::Sentence if i == 0 => upper_start,
+				Case::Sentence => lower_start,
+				Case::Title if i == 0 || i == last => upper_start,
+				Case::Title => {
+					let bare = word.trim_matches(|c: char| !c.is_alphanumeric());
+					upper_start || MINOR_WORDS.contains(&bare)
+				}
+			}
+		})
+	}
+}
+
+fn load_case() -> anyhow::Result<Case> {
+	let table = config::load_table("check.headings")?;
+	let name = table
+		.and_then(|t| t.get_str("case"))
+		.unwrap_or_else(|| "any".to_string());
+	match name.as_str() {
+		"any" => Ok(Case::Any),
+		"sentence" => Ok(Case::Sentence),
+		"title" => Ok(Case::Title),
+		other => {
+			bail!("unknown heading case '{other}' in [check.headings] (expected: any, sentence, title)")
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn headings() {
+		assert_eq!(parse_heading("## Title"), Some((2, "Title")));
+		assert_eq!(parse_heading("## Title ##"), Some((2, "Title")));
+		assert_eq!(parse_heading("## C#"), Some((2, "C#")));
+		assert_eq!(parse_heading("#hashtag"), None);
+		assert_eq!(parse_heading("####### seven"), None);
+	}
+
+	#[test]
+	fn sentence_case() {
+		assert!(Case::Sentence.accepts("Rolling the snowball"));
+		assert!(Case::Sentence.accepts("Using JSON with `HashMap`"));
+		assert!(!Case::Sentence.accepts("Rolling The Snowball"));
+		assert!(!Case::Sentence.accepts("rolling the snowball"));
+	}
+
+	#[test]
+	fn title_case() {
+		assert!(Case::Title.accepts("Rolling the Snowball"));
+		assert!(Case::Title.accepts("What It Is Made Of"));
+		assert!(!Case::Title.accepts("Rolling the snowball"));
+	}
+}
@@ -1,38 +1,93 @@
 use std::path::Path;
 
+use anyhow::bail;
 use sha2::{Digest, Sha256};
 
-use super::collect_md_files;
+use super::{collect_md_files, glob_match, rel_path};
+use crate::config;
 
-const THRESHOLD: usize = 100;
+const DEFAULT_MAX: usize = 100;
+
+const DEFAULT_EXEMPT: &[&str] = &["**/CHANGELOG.md", "**/README.md"];
+
+/// `[check.line_count]` plus per-glob `[[check.line_count.limit]]` thresholds.
+struct Policy {
+	max: usize,
+	/// Globs and their thresholds, first match wins.
+	limits: Vec<(Vec<String>, usize)>,
+	exempt: Vec<String>,
+	exclude_code_blocks: bool,
+	exclude_tables: bool,
+	exclude_blank: bool,
+}
+
+impl Policy {
+	fn load() -> anyhow::Result<Self> {
+		let tables = config::load_tables()?;
+		let main = tables.iter().find(|t| t.name == "check.line_count");
+		let flag = |key: &str| main.and_then(|t| t.get_bool(key)).unwrap_or(false);
+
+		let mut limits = Vec::new();
+		for table in tables.iter().filter(|t| t.name == "check.line_count.limit") {
+			let (Some(paths), Some(max)) = (table.get_list("paths"), table.get_usize("max")) else {
+				bail!("[[check.line_count.limit]] needs both `paths` and `max`");
+			};
+			limits.push((paths, max));
+		}
+
+		Ok(Policy {
+			max: main.and_then(|t| t.get_usize("max")).unwrap_or(DEFAULT_MAX),
+			limits,
+			exempt: main
+				.and_then(|t| t.get_list("exempt"))
+				.unwrap_or_else(|| DEFAULT_EXEMPT.iter().map(|s| s.to_string()).collect()),
+			exclude_code_blocks: flag("exclude_code_blocks"),
+			exclude_tables: flag("exclude_tables"),
+			exclude_blank: flag("exclude_blank"),
+		})
+	}
+
+	fn threshold(&self, rel: &str) -> usize {
+		self
+			.limits
+			.iter()
+			.find(|(paths, _)| paths.iter().any(|g| glob_match(g, rel)))
+			.map_or(self.max, |(_, max)| *max)
+	}
+}
 
 pub fn run(spec_dir: &Path, repo_root: &Path) -> anyhow::Result<Vec<String>> {
 	let files = collect_md_files(spec_dir)?;
+	let policy = Policy::load()?;
 	let allowlist = load_allowlist_from_config()?;
 	let mut errors = Vec::new();
 
 	for file in &files {
-		let name = file.file_name().unwrap_or_default().to_string_lossy();
-		if name == "CHANGELOG.md" || name == "README.md" {
+		let rel = rel_path(file, repo_root);
+		if policy.exempt.iter().any(|g| glob_match(g, &rel)) {
 			continue;
 		}
 
 		let content = std::fs::read_to_string(file)?;
-		let count = content.lines().count();
+		let counted = counted_lines(&content, &policy);
+		let count = counted.iter().filter(|&&c| c).count();
+		let threshold = policy.threshold(&rel);
 
-		if count > THRESHOLD {
-			let rel_path = file.strip_prefix(repo_root).unwrap_or(file);
+		if count > threshold {
 			let hash = format!("{:x}", Sha256::digest(&content));
 
 			let allowed = allowlist
 				.iter()
-				.any(|e| e.file == rel_path.to_string_lossy() && e.check == "line-count" && e.hash == hash);
+				.any(|e| e.file == rel && e.check == "line-count" && e.hash == hash);
 
 			if !allowed {
-				errors.push(format!(
-					"OVER {THRESHOLD} LINES: {} ({count} lines)",
-					file.display()
-				));
+				let mut msg = format!("OVER {threshold} LINES: {} ({count} lines)", file.display());
+				if let Some((line_num, heading, len)) = longest_section(&content, &counted) {
+					msg.push_str(&format!(
+						"; longest section \"{heading}\" at line {line_num} ({len} lines)"
+					));
+				}
+				errors.push(msg);
 			}
 		}
 	}
@@ -40,6 +95,46 @@ pub fn run(spec_dir: &Path, repo_root: &Path) -> anyhow::Result<Vec<String>> {
 	Ok(errors)
 }
 
+/// Whether each line counts toward the limit under the policy's exclusions.
+fn counted_lines(content: &str, policy: &Policy) -> Vec<bool> {
+	let mut in_code_block = false;
+	content
+		.lines()
+		.map(|line| {
+			let was_in_code = in_code_block;
+			in_code_block = super::is_in_code_block(line, in_code_block);
+			let code = was_in_code || in_code_block;
+			!((policy.exclude_code_blocks && code)
+				|| (policy.exclude_tables && !code && line.trim_start().starts_with('|'))
+				|| (policy.exclude_blank && line.trim().is_empty()))
+		})
+		.collect()
+}
+
+/// The `##` section with the most counted lines: its line, heading and size.
+fn longest_section<'a>(content: &'a str, counted: &[bool]) -> Option<(usize, &'a str, usize)> {
+	let mut sections: Vec<(usize, &str, usize)> = Vec::new();
+	let mut in_code_block = false;
+
+	for (i, line) in content.lines().enumerate() {
+		in_code_block = super::is_in_code_block(line, in_code_block);
+		let heading = !in_code_block && (line.starts_with("## ") || line.starts_with("# "));
+		if heading {
+			sections.push((i + 1, line.trim(), 0));
+		}
+		if let Some(section) = sections.last_mut()
+			&& counted.get(i).copied().unwrap_or(false)
+		{
+			section.2 += 1;
+		}
+	}
+
+	sections
+		.into_iter()
+		.filter(|(_, heading, _)| heading.starts_with("## "))
+		.max_by_key(|&(line_num, _, len)| (len, std::cmp::Reverse(line_num)))
+}
+
 struct AllowEntry {
 	file: String,
 	check: String,
@@ -47,7 +142,7 @@ struct AllowEntry {
 }
 
 fn load_allowlist_from_config() -> anyhow::Result<Vec<AllowEntry>> {
-	let Some(path) = crate::config::find_config() else {
+	let Some(path) = config::find_config() else {
 		return Ok(Vec::new());
 	};
 
@@ -115,3 +210,39 @@ fn load_allowlist_from_config() -> anyhow::Result<Vec<AllowEntry>> {
 
 	Ok(entries)
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn policy() -> Policy {
+		Policy {
+			max: 3,
+			limits: vec![(vec!["spec/proto/**".to_string()], 10)],
+			exempt: Vec::new(),
+			exclude_code_blocks: true,
+			exclude_tables: true,
+			exclude_blank: true,
+		}
+	}
+
+	#[test]
+	fn exclusions() {
+		let content = "# T\n\n```\ncode\n```\n| a |\ntext\n";
+		let counted = counted_lines(content, &policy());
+		assert_eq!(counted, [true, false, false, false, false, false, true]);
+	}
+
+	#[test]
+	fn thresholds() {
+		assert_eq!(policy().threshold("spec/proto/wire.md"), 10);
+		assert_eq!(policy().threshold("spec/net.md"), 3);
+	}
+
+	#[test]
+	fn longest() {
+		let content = "# T\n## A\na\n## B\nb\nb\n### B.1\nb\n## C\n";
+		let counted = vec![true; content.lines().count()];
+		assert_eq!(longest_section(content, &counted), Some((4, "## B", 5)));
+	}
+}
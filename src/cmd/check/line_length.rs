@@ -0,0 +1,430 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::bail;
+
+use super::collect_pages;
+use super::structure::parse_item;
+use crate::config;
+
+const DEFAULT_MAX: usize = 100;
+
+/// Abbreviations whose trailing period does not end a sentence.
+const ABBREVIATIONS: &[&str] = &["e.g.", "i.e.", "etc.", "vs.", "cf.", "approx.", "no."];
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+	/// Wrap paragraphs at `max` characters.
+	Max,
+	/// One sentence per line, however long.
+	Sentence,
+}
+
+pub struct Policy {
+	pub mode: Mode,
+	pub max: usize,
+}
+
+/// `[check.line_length]`; the check and the fixer are off without it.
+pub fn load_policy() -> anyhow::Result<Option<Policy>> {
+	let Some(table) = config::load_table("check.line_length")? else {
+		return Ok(None);
+	};
+	let mode = match table.get_str("mode").as_deref().unwrap_or("max") {
+		"max" => Mode::Max,
+		"sentence" => Mode::Sentence,
+		other => bail!("unknown mode '{other}' in [check.line_length] (expected: max, sentence)"),
+	};
+	let max = table.get_usize("max").unwrap_or(DEFAULT_MAX);
+	Ok(Some(Policy { mode, max }))
+}
+
+pub fn run(spec_dir: &Path) -> anyhow::Result<Vec<String>> {
+	let Some(policy) = load_policy()? else {
+		return Ok(Vec::new());
+	};
+	let mut errors = Vec::new();
+
+	for file in &files(spec_dir)? {
+		let content = std::fs::read_to_string(file)?;
+		for (line_num, kind, msg) in check(&content, &policy) {
+			errors.push(format!("{kind}: {}:{line_num} — {msg}", file.display()));
+		}
+	}
+
+	Ok(errors)
+}
+
+/// Pages the policy covers, which `qwq fmt` may rewrap.
+pub fn files(spec_dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+	collect_pages(spec_dir)
+}
+
+/// Line number, kind and message for every line breaking the policy.
+fn check(content: &str, policy: &Policy) -> Vec<(usize, &'static str, String)> {
+	let mut findings = Vec::new();
+
+	for block in blocks(content) {
+		let Block::Paragraph(p) = block else {
+			continue;
+		};
+		for (i, text) in p.lines.iter().enumerate() {
+			let line_num = p.start + i + 1;
+			let prefix = if i == 0 {
+				&p.first_prefix
+			} else {
+				&p.cont_prefix
+			};
+			let words = tokens(text);
+
+			match policy.mode {
+				Mode::Max => {
+					let len = prefix.chars().count() + text.trim_end().chars().count();
+					if len > policy.max && words.len() > 1 && !is_link_only(text) {
+						findings.push((
+							line_num,
+							"LINE-LENGTH",
+							format!("{len} characters (max {})", policy.max),
+						));
+					}
+				}
+				Mode::Sentence => {
+					let next = p.lines.get(i + 1).and_then(|l| tokens(l).first().copied());
+					let sentences = split_sentences(&words, next);
+					if sentences.len() > 1 {
+						findings.push((
+							line_num,
+							"SENTENCE",
+							format!("{} sentences on one line", sentences.len()),
+						));
+					}
+					let ends = words.last().is_some_and(|w| ends_sentence(w, next));
+					if next.is_some() && !ends {
+						findings.push((
+							line_num,
+							"SENTENCE",
+							"sentence continues on the next line".to_string(),
+						));
+					}
+				}
+			}
+		}
+	}
+
+	findings
+}
+
+/// Rewrap every paragraph in `content` according to `policy`.
+pub fn rewrap(content: &str, policy: &Policy) -> String {
+	let mut out: Vec<String> = Vec::new();
+
+	for block in blocks(content) {
+		match block {
+			Block::Verbatim(line) => out.push(line.to_string()),
+			Block::Paragraph(p) if p.lines.len() == 1 && is_link_only(p.lines[0]) => {
+				out.push(format!("{}{}{}", p.first_prefix, p.lines[0], p.hard_break));
+			}
+			Block::Paragraph(p) => {
+				let joined = p.lines.join(" ");
+				let words = tokens(&joined);
+				let lines = match policy.mode {
+					Mode::Max => fill(&words, policy.max, &p.first_prefix, &p.cont_prefix),
+					Mode::Sentence => split_sentences(&words, None)
+						.into_iter()
+						.map(|s| s.join(" "))
+						.collect(),
+				};
+				let count = lines.len();
+				for (i, line) in lines.into_iter().enumerate() {
+					let prefix = if i == 0 {
+						&p.first_prefix
+					} else {
+						&p.cont_prefix
+					};
+					let suffix = if i + 1 == count { p.hard_break } else { "" };
+					out.push(format!("{prefix}{line}{suffix}"));
+				}
+			}
+		}
+	}
+
+	let mut result = out.join("\n");
+	if content.ends_with('\n') {
+		result.push('\n');
+	}
+	result
+}
+
+enum Block<'a> {
+	/// Headings, tables, code blocks, blank lines and other lines kept as is.
+	Verbatim(&'a str),
+	Paragraph(Paragraph<'a>),
+}
+
+struct Paragraph<'a> {
+	/// Index of the first line.
+	start: usize,
+	/// List marker, blockquote marker or indentation of the first line.
+	first_prefix: String,
+	/// The same for continuation lines.
+	cont_prefix: String,
+	/// Line texts without their prefixes.
+	lines: Vec<&'a str>,
+	/// Trailing hard break ("  " or "\\") kept on the last line.
+	hard_break: &'a str,
+}
+
+fn blocks(content: &str) -> Vec<Block<'_>> {
+	let lines: Vec<&str> = content.lines().collect();
+	let mut blocks = Vec::new();
+	let mut in_code_block = false;
+	let mut in_list = false;
+	let mut prev_blank = true;
+	let mut i = super::front_matter::line_count(content).min(lines.len());
+	blocks.extend(lines[..i].iter().map(|l| Block::Verbatim(l)));
+
+	while i < lines.len() {
+		let line = lines[i];
+		in_code_block = super::is_in_code_block(line, in_code_block);
+		let indent = line.len() - line.trim_start().len();
+		let indented_code = indent >= 4 && prev_blank && !in_list;
+
+		if in_code_block || line.starts_with("```") || is_verbatim(line) || indented_code {
+			blocks.push(Block::Verbatim(line));
+			prev_blank = line.trim().is_empty();
+			i += 1;
+			continue;
+		}
+
+		let (first_prefix, cont_prefix, text) = split_prefix(line);
+		let quoted = first_prefix.starts_with('>');
+		in_list = match parse_item(line) {
+			Some(_) => true,
+			None => in_list && indent > 0,
+		};
+
+		let mut paragraph = Paragraph {
+			start: i,
+			first_prefix,
+			cont_prefix,
+			lines: vec![text],
+			hard_break: "",
+		};
+		i += 1;
+		loop {
+			let last = paragraph.lines.len() - 1;
+			if let Some(kept) = hard_break(paragraph.lines[last]) {
+				paragraph.hard_break = kept;
+				let text = paragraph.lines[last];
+				paragraph.lines[last] = text[..text.len() - kept.len()].trim_end();
+				break;
+			}
+			let Some(&next) = lines.get(i) else {
+				break;
+			};
+			if next.starts_with("```")
+				|| is_verbatim(next)
+				|| parse_item(next).is_some()
+				|| next.trim_start().starts_with('>') != quoted
+			{
+				break;
+			}
+			let text = next.trim_start();
+			let text = if quoted {
+				text.trim_start_matches('>').trim_start()
+			} else {
+				text
+			};
+			paragraph.lines.push(text);
+			i += 1;
+		}
+		for text in &mut paragraph.lines {
+			*text = text.trim_end();
+		}
+
+		prev_blank = false;
+		blocks.push(Block::Paragraph(paragraph));
+	}
+
+	blocks
+}
+
+fn is_verbatim(line: &str) -> bool {
+	let trimmed = line.trim();
+	trimmed.is_empty()
+		|| trimmed.starts_with('#')
+		|| trimmed.starts_with('|')
+		|| trimmed.starts_with('<')
+		|| (trimmed.starts_with('[') && trimmed.contains("]: "))
+		// thematic breaks and setext underlines
+		|| (trimmed.len() >= 3 && trimmed.chars().all(|c| "-*_= ".contains(c)))
+}
+
+/// First-line prefix, continuation prefix and text of a paragraph's first
+/// line; trailing spaces are kept so hard breaks can be detected.
+fn split_prefix(line: &str) -> (String, String, &str) {
+	if let Some(item) = parse_item(line) {
+		let prefix = &line[..item.content];
+		return (
+			prefix.to_string(),
+			" ".repeat(item.content),
+			&line[item.content..],
+		);
+	}
+	let indent = &line[..line.len() - line.trim_start().len()];
+	let rest = &line[indent.len()..];
+	if let Some(quoted) = rest.strip_prefix('>') {
+		let prefix = format!("{indent}> ");
+		return (prefix.clone(), prefix, quoted.trim_start());
+	}
+	(indent.to_string(), indent.to_string(), rest)
+}
+
+fn hard_break(line: &str) -> Option<&'static str> {
+	if line.ends_with('\\') {
+		Some("\\")
+	} else if line.ends_with("  ") {
+		Some("  ")
+	} else {
+		None
+	}
+}
+
+/// Whitespace-separated words, keeping `inline code` spans whole.
+fn tokens(text: &str) -> Vec<&str> {
+	let mut tokens = Vec::new();
+	let mut start: Option<usize> = None;
+	let mut in_code = false;
+
+	for (i, c) in text.char_indices() {
+		if c == '`' {
+			in_code = !in_code;
+		}
+		if c.is_whitespace() && !in_code {
+			if let Some(s) = start.take() {
+				tokens.push(&text[s..i]);
+			}
+		} else if start.is_none() {
+			start = Some(i);
+		}
+	}
+	if let Some(s) = start {
+		tokens.push(&text[s..]);
+	}
+	tokens
+}
+
+/// A line holding nothing but a link, autolink or bare URL.
+fn is_link_only(text: &str) -> bool {
+	let text = text.trim();
+	let bare_url =
+		!text.contains(' ') && (text.starts_with("http://") || text.starts_with("https://"));
+	let autolink = text.starts_with('<') && text.ends_with('>');
+	let link = text.starts_with('[') && text.ends_with(')') && text.contains("](");
+	bare_url || autolink || link
+}
+
+/// Words that would start a new block if they began a line.
+fn starts_block(word: &str) -> bool {
+	matches!(word, "-" | "+" | "*" | ">" | "|")
+		|| word.starts_with('#')
+		|| word.starts_with('>')
+		|| parse_item(&format!("{word} x")).is_some()
+}
+
+/// Greedy fill to `max` columns, never starting a line with block syntax.
+fn fill(words: &[&str], max: usize, first_prefix: &str, cont_prefix: &str) -> Vec<String> {
+	let mut lines: Vec<String> = Vec::new();
+	let mut current = String::new();
+
+	for word in words {
+		let prefix = if lines.is_empty() {
+			first_prefix
+		} else {
+			cont_prefix
+		};
+		let width = prefix.chars().count() + current.chars().count() + 1 + word.chars().count();
+		if !current.is_empty() && width > max && !starts_block(word) {
+			lines.push(std::mem::take(&mut current));
+		}
+		if !current.is_empty() {
+			current.push(' ');
+		}
+		current.push_str(word);
+	}
+	if !current.is_empty() {
+		lines.push(current);
+	}
+	lines
+}
+
+fn split_sentences<'a>(words: &[&'a str], after: Option<&str>) -> Vec<Vec<&'a str>> {
+	let mut sentences: Vec<Vec<&str>> = Vec::new();
+	let mut current = Vec::new();
+
+	for (i, word) in words.iter().enumerate() {
+		current.push(*word);
+		let next = words.get(i + 1).copied().or(after);
+		let breakable = words.get(i + 1).is_none_or(|n| !starts_block(n));
+		if ends_sentence(word, next) && breakable {
+			sentences.push(std::mem::take(&mut current));
+		}
+	}
+	if !current.is_empty() {
+		sentences.push(current);
+	}
+	sentences
+}
+
+fn ends_sentence(word: &str, next: Option<&str>) -> bool {
+	let bare = word.trim_end_matches([')', '"', '\'', '*', '_', '”', '’']);
+	if !bare.ends_with(['.', '?', '!', '。', '？', '！']) {
+		return false;
+	}
+	if ABBREVIATIONS.contains(&bare.to_lowercase().as_str()) {
+		return false;
+	}
+	// "v1.2." and "3." continue; the next sentence starts with a capital
+	next.is_none_or(|n| {
+		n.trim_start_matches(['(', '"', '\'', '*', '_', '[', '`', '“', '‘'])
+			.starts_with(|c: char| c.is_uppercase() || !c.is_alphabetic())
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn policy(mode: Mode, max: usize) -> Policy {
+		Policy { mode, max }
+	}
+
+	#[test]
+	fn wraps_at_max() {
+		let content = "# Title\n\n- one two three four five six\n  seven\n\n| a b c d e f g |\n\n[a b c d](https://x.y/z)\n";
+		let fixed = rewrap(content, &policy(Mode::Max, 16));
+		assert_eq!(
+			fixed,
+			"# Title\n\n- one two three\n  four five six\n  seven\n\n| a b c d e f g |\n\n[a b c d](https://x.y/z)\n"
+		);
+		assert!(check(&fixed, &policy(Mode::Max, 16)).is_empty());
+		assert_eq!(check(content, &policy(Mode::Max, 16)).len(), 1);
+	}
+
+	#[test]
+	fn keeps_code_spans_and_breaks() {
+		let content = "Use `a b c` here  \nand - here.\n";
+		assert_eq!(
+			rewrap(content, &policy(Mode::Max, 8)),
+			"Use\n`a b c`\nhere  \nand -\nhere.\n"
+		);
+	}
+
+	#[test]
+	fn one_sentence_per_line() {
+		let content = "> First one, e.g. this. Second\n> one? Yes.\n";
+		let fixed = rewrap(content, &policy(Mode::Sentence, 0));
+		assert_eq!(fixed, "> First one, e.g. this.\n> Second one?\n> Yes.\n");
+		assert_eq!(check(content, &policy(Mode::Sentence, 0)).len(), 3);
+		assert!(check(&fixed, &policy(Mode::Sentence, 0)).is_empty());
+	}
+}
@@ -1,10 +1,14 @@
-use std::path::Path;
+use std::path::{Component, Path, PathBuf};
 
 use super::collect_md_files;
 
-pub fn run(spec_dir: &Path) -> anyhow::Result<Vec<String>> {
+/// Check relative links; with `external`, also request every `http(s)://`
+/// link (see `external::run`). Local images are left to the assets check.
+pub fn run(spec_dir: &Path, repo_root: &Path, external: bool) -> anyhow::Result<Vec<String>> {
 	let files = collect_md_files(spec_dir)?;
+	let root = normalize(repo_root);
 	let mut errors = Vec::new();
+	let mut urls: Vec<(String, String)> = Vec::new();
 
 	for file in &files {
 		let content = std::fs::read_to_string(file)?;
@@ -16,6 +20,7 @@ pub fn run(spec_dir: &Path) -> anyhow::Result<Vec<String>> {
 			if in_code_block || line.starts_with("```") {
 				continue;
 			}
+			let location = format!("{}:{}", file.display(), line_num + 1);
 
 			let mut rest = line;
 			while let Some(start) = rest.find("](") {
@@ -23,32 +28,260 @@ pub fn run(spec_dir: &Path) -> anyhow::Result<Vec<String>> {
 				let Some(end) = after.find(')') else {
 					break;
 				};
-				let target = &after[..end];
+				let target = link_destination(&after[..end]);
+				let is_image = rest[..start]
+					.rfind('[')
+					.is_some_and(|open| rest[..open].ends_with('!'));
 				rest = &after[end + 1..];
 
-				if target.starts_with("http://")
-					|| target.starts_with("https://")
-					|| target.starts_with('#')
-				{
+				if target.starts_with("http://") || target.starts_with("https://") {
+					urls.push((target.to_string(), location.clone()));
+					continue;
+				}
+				if is_image || target.starts_with("mailto:") || target.starts_with('#') {
 					continue;
 				}
 
-				let target_path = target.split('#').next().unwrap_or(target);
+				let (target_path, fragment) = target.split_once('#').unwrap_or((target, ""));
 				if target_path.is_empty() {
 					continue;
 				}
+				let resolved = match resolve_target(dir, &root, target_path) {
+					Ok(resolved) => resolved,
+					Err((kind, note)) => {
+						errors.push(format!("{kind}: {location} -> {target_path}{note}"));
+						continue;
+					}
+				};
 
-				let resolved = dir.join(target_path);
-				if !resolved.exists() {
+				let is_md = resolved.extension().is_some_and(|ext| ext == "md");
+				if !is_md
+					&& let Some(range) = line_range(fragment)
+					&& let Some(problem) = check_line_range(&resolved, range)
+				{
 					errors.push(format!(
-						"BROKEN LINK: {}:{} -> {target_path}",
-						file.display(),
-						line_num + 1
+						"BAD LINE ANCHOR: {location} -> {target_path}#{fragment} ({problem})"
 					));
 				}
 			}
 		}
 	}
 
+	if external {
+		errors.extend(super::external::run(&urls, repo_root)?);
+	}
 	Ok(errors)
 }
+
+/// The destination of `[text](dest "title")` or `[text](<dest>)`.
+pub fn link_destination(inner: &str) -> &str {
+	let inner = inner.trim();
+	if let Some(bracketed) = inner.strip_prefix('<') {
+		return bracketed.split('>').next().unwrap_or(bracketed);
+	}
+	inner.split_whitespace().next().unwrap_or(inner)
+}
+
+/// The file a relative link target points to, or why it does not resolve
+/// inside the repository: the error kind and a note for the end of the message.
+pub fn resolve_target(
+	dir: &Path,
+	root: &Path,
+	target_path: &str,
+) -> Result<PathBuf, (&'static str, String)> {
+	if target_path.starts_with('/') || target_path.starts_with("file:") || is_drive_path(target_path)
+	{
+		return Err(("ABSOLUTE LINK", " (use a relative path)".to_string()));
+	}
+	let Some(decoded) = percent_decode(target_path) else {
+		return Err(("BAD LINK ENCODING", String::new()));
+	};
+
+	let resolved = normalize(&dir.join(&decoded));
+	let Ok(inside) = resolved.strip_prefix(root) else {
+		return Err(("LINK ESCAPES REPO", String::new()));
+	};
+
+	match find_on_disk(root, inside) {
+		Lookup::Exact => Ok(resolved),
+		Lookup::Missing => Err(("BROKEN LINK", String::new())),
+		Lookup::CaseMismatch(actual) => Err((
+			"LINK CASE MISMATCH",
+			format!(
+				" (on disk: {})",
+				actual.to_string_lossy().replace('\\', "/")
+			),
+		)),
+	}
+}
+
+/// `L20` or `L20-L35`, as GitHub and most code hosts link line ranges.
+/// `Some(None)` for a fragment that looks like a line anchor but is malformed.
+fn line_range(fragment: &str) -> Option<Option<(usize, usize)>> {
+	let rest = fragment.strip_prefix('L')?;
+	if !rest.starts_with(|c: char| c.is_ascii_digit()) {
+		return None;
+	}
+	let parse = |n: &str| n.parse::<usize>().ok().filter(|&n| n > 0);
+	Some(match rest.split_once("-L") {
+		Some((start, end)) => parse(start).zip(parse(end)).filter(|(a, b)| a <= b),
+		None => parse(rest).map(|n| (n, n)),
+	})
+}
+
+fn check_line_range(path: &Path, range: Option<(usize, usize)>) -> Option<String> {
+	let Some((_, end)) = range else {
+		return Some("expected #L<n> or #L<a>-L<b> with 1 <= a <= b".to_string());
+	};
+	let bytes = match std::fs::read(path) {
+		Ok(bytes) => bytes,
+		Err(e) => return Some(format!("cannot read: {e}")),
+	};
+	// a final line without a newline still counts
+	let lines = bytes.iter().filter(|&&b| b == b'\n').count()
+		+ usize::from(bytes.last().is_some_and(|&b| b != b'\n'));
+	(end > lines).then(|| format!("file has {lines} lines"))
+}
+
+fn is_drive_path(path: &str) -> bool {
+	let bytes = path.as_bytes();
+	bytes.len() >= 3
+		&& bytes[0].is_ascii_alphabetic()
+		&& bytes[1] == b':'
+		&& (bytes[2] == b'/' || bytes[2] == b'\\')
+}
+
+/// Decode `%XX` escapes; `None` for malformed escapes or invalid UTF-8.
+fn percent_decode(text: &str) -> Option<String> {
+	let bytes = text.as_bytes();
+	let mut decoded = Vec::with_capacity(bytes.len());
+	let mut i = 0;
+
+	while i < bytes.len() {
+		if bytes[i] == b'%' {
+			let hex = std::str::from_utf8(bytes.get(i + 1..i + 3)?).ok()?;
+			decoded.push(u8::from_str_radix(hex, 16).ok()?);
+			i += 3;
+		} else {
+			decoded.push(bytes[i]);
+			i += 1;
+		}
+	}
+
+	String::from_utf8(decoded).ok()
+}
+
+/// Resolve `.` and `..` without touching the filesystem.
+pub fn normalize(path: &Path) -> PathBuf {
+	let mut result = PathBuf::new();
+	for component in path.components() {
+		match component {
+			Component::CurDir => {}
+			Component::ParentDir => {
+				if !result.pop() {
+					result.push("..");
+				}
+			}
+			other => result.push(other),
+		}
+	}
+	result
+}
+
+enum Lookup {
+	Exact,
+	/// Exists only when names are compared case-insensitively.
+	CaseMismatch(PathBuf),
+	Missing,
+}
+
+/// Walk `rel` from `root` one directory listing at a time, so the exact
+/// on-disk spelling of every component is compared.
+fn find_on_disk(root: &Path, rel: &Path) -> Lookup {
+	let mut dir = root.to_path_buf();
+	let mut actual = PathBuf::new();
+	let mut mismatch = false;
+
+	for component in rel.components() {
+		let name = component.as_os_str().to_string_lossy();
+		let Ok(entries) = std::fs::read_dir(&dir) else {
+			return Lookup::Missing;
+		};
+		let names: Vec<String> = entries
+			.filter_map(|e| e.ok())
+			.map(|e| e.file_name().to_string_lossy().into_owned())
+			.collect();
+
+		let found = if names.iter().any(|n| *n == name) {
+			name.into_owned()
+		} else if let Some(n) = names
+			.iter()
+			.find(|n| n.to_lowercase() == name.to_lowercase())
+		{
+			mismatch = true;
+			n.clone()
+		} else {
+			return Lookup::Missing;
+		};
+		dir.push(&found);
+		actual.push(&found);
+	}
+
+	if mismatch {
+		Lookup::CaseMismatch(actual)
+	} else {
+		Lookup::Exact
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn decoding() {
+		assert_eq!(percent_decode("a%20b.md").as_deref(), Some("a b.md"));
+		assert_eq!(percent_decode("caf%C3%A9.md").as_deref(), Some("café.md"));
+		assert_eq!(percent_decode("a%2"), None);
+		assert_eq!(percent_decode("a%zz"), None);
+	}
+
+	#[test]
+	fn destinations() {
+		assert_eq!(link_destination("a.md \"Title\""), "a.md");
+		assert_eq!(link_destination("<a b.md>"), "a b.md");
+	}
+
+	#[test]
+	fn line_anchors() {
+		assert_eq!(line_range("L20"), Some(Some((20, 20))));
+		assert_eq!(line_range("L20-L35"), Some(Some((20, 35))));
+		assert_eq!(line_range("L35-L20"), Some(None));
+		assert_eq!(line_range("L0"), Some(None));
+		assert_eq!(line_range("L2x"), Some(None));
+		assert_eq!(line_range("Lorem"), None);
+		assert_eq!(line_range("section"), None);
+	}
+
+	#[test]
+	fn escapes_and_absolute() {
+		let root = Path::new("/repo");
+		let dir = Path::new("/repo/spec");
+		assert_eq!(
+			resolve_target(dir, root, "../../etc/passwd")
+				.map_err(|e| e.0)
+				.err(),
+			Some("LINK ESCAPES REPO")
+		);
+		assert_eq!(
+			resolve_target(dir, root, "/etc/passwd")
+				.map_err(|e| e.0)
+				.err(),
+			Some("ABSOLUTE LINK")
+		);
+		assert_eq!(
+			resolve_target(dir, root, "C:/x.md").map_err(|e| e.0).err(),
+			Some("ABSOLUTE LINK")
+		);
+	}
+}
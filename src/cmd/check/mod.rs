@@ -1,15 +1,32 @@
+pub mod assets;
 pub mod changelog;
+pub mod code_blocks;
+pub mod external;
 pub mod forbidden;
+pub mod front_matter;
+pub mod headings;
 pub mod line_count;
+pub mod line_length;
 pub mod links;
 pub mod naming;
+pub mod requirements;
+pub mod rfc2119;
+pub mod spelling;
+pub mod structure;
 pub mod terminology;
+pub mod typography;
+pub mod unicode;
+pub mod version;
+pub mod walk;
+pub mod whitespace;
 
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context, bail};
 use clap::Subcommand;
 
+use crate::config;
+
 #[derive(clap::Args)]
 pub struct Args {
 	#[command(subcommand)]
@@ -19,17 +36,70 @@ pub struct Args {
 #[derive(Subcommand)]
 pub enum CheckCommand {
 	/// Check internal markdown links
-	Links,
+	Links {
+		/// Also request http(s) links, using the [check.links] settings and cache
+		#[arg(long)]
+		external: bool,
+	},
+	/// Check images and other assets: existence, alt text, size, references
+	Assets,
 	/// Check file line counts
 	LineCount,
-	/// Check file and directory naming
+	/// Check prose line length or one-sentence-per-line ([check.line_length])
+	LineLength,
+	/// Check file and directory naming ([[check.naming.rule]] styles, collisions)
 	Naming,
-	/// Check for forbidden patterns (emoji, etc.)
+	/// Check for forbidden patterns (emoji, hidden characters, [[check.forbidden]] rules)
 	Forbidden,
-	/// Check terminology consistency
+	/// Check terminology against spec/glossary.md and [[check.terminology]]
 	Terminology,
+	/// Check page front matter against [check.front_matter]
+	FrontMatter,
+	/// Check markdown heading structure and case
+	Headings,
+	/// Check markdown table and list well-formedness
+	Structure {
+		/// Rewrite list markers and numbering
+		#[arg(long)]
+		fix: bool,
+	},
+	/// Check code block language tags and json/toml/yaml syntax
+	CodeBlocks,
+	/// Check requirement IDs are unique and references resolve
+	Requirements,
+	/// Check RFC 2119 requirement keyword usage
+	Rfc2119 {
+		/// Print every normative statement per file
+		#[arg(long)]
+		report: bool,
+	},
+	/// Check spelling of spec prose
+	Spelling {
+		/// Add words to the project dictionary instead of checking
+		#[arg(long = "add-word", value_name = "WORD")]
+		add_word: Vec<String>,
+	},
+	/// Check CJK/Latin spacing, punctuation and quotes
+	Typography,
 	/// Check CHANGELOG format
-	Changelog,
+	Changelog {
+		/// Also reject edits to entries already released on main
+		#[arg(long)]
+		vcs: bool,
+	},
+	/// Check whitespace and encoding hygiene
+	#[command(alias = "encoding")]
+	Whitespace {
+		/// Rewrite files to fix what can be fixed
+		#[arg(long)]
+		fix: bool,
+	},
+	/// Check spec/VERSION format
+	Version {
+		/// Also require a VERSION bump and CHANGELOG entry for spec changes since main
+		#[arg(long)]
+		vcs: bool,
+	},
 }
 
 pub fn execute(args: Args) -> anyhow::Result<()> {
@@ -41,23 +111,77 @@ pub fn execute(args: Args) -> anyhow::Result<()> {
 
 	match args.command {
 		None => run_all(&spec_dir, &repo_root),
-		Some(CheckCommand::Links) => run_one("links", links::run(&spec_dir)),
+		Some(CheckCommand::Links { external }) => {
+			run_one("links", links::run(&spec_dir, &repo_root, external))
+		}
+		Some(CheckCommand::Assets) => run_one("assets", assets::run(&spec_dir, &repo_root)),
 		Some(CheckCommand::LineCount) => run_one("line-count", line_count::run(&spec_dir, &repo_root)),
-		Some(CheckCommand::Naming) => run_one("naming", naming::run(&spec_dir)),
-		Some(CheckCommand::Forbidden) => run_one("forbidden", forbidden::run(&spec_dir)),
+		Some(CheckCommand::LineLength) => run_one("line-length", line_length::run(&spec_dir)),
+		Some(CheckCommand::Naming) => run_one("naming", naming::run(&spec_dir, &repo_root)),
+		Some(CheckCommand::Forbidden) => run_one("forbidden", forbidden::run(&spec_dir, &repo_root)),
 		Some(CheckCommand::Terminology) => run_one("terminology", terminology::run(&spec_dir)),
-		Some(CheckCommand::Changelog) => run_one("changelog", changelog::run(&spec_dir)),
+		Some(CheckCommand::FrontMatter) => {
+			run_one("front-matter", front_matter::run(&spec_dir, &repo_root))
+		}
+		Some(CheckCommand::Headings) => run_one("headings", headings::run(&spec_dir)),
+		Some(CheckCommand::Structure { fix }) => run_one("structure", structure::run(&spec_dir, fix)),
+		Some(CheckCommand::CodeBlocks) => run_one("code-blocks", code_blocks::run(&spec_dir)),
+		Some(CheckCommand::Requirements) => {
+			run_one("requirements", requirements::run(&spec_dir, &repo_root))
+		}
+		Some(CheckCommand::Rfc2119 { report }) => {
+			if report {
+				rfc2119::report(&spec_dir)?;
+			}
+			run_one("rfc2119", rfc2119::run(&spec_dir))
+		}
+		Some(CheckCommand::Spelling { add_word }) if !add_word.is_empty() => {
+			spelling::add_words(&repo_root, &add_word)
+		}
+		Some(CheckCommand::Spelling { .. }) => {
+			run_one("spelling", spelling::run(&spec_dir, &repo_root))
+		}
+		Some(CheckCommand::Typography) => run_one("typography", typography::run(&spec_dir)),
+		Some(CheckCommand::Changelog { vcs }) => {
+			let mut result = changelog::run(&spec_dir);
+			if vcs && let Ok(errors) = &mut result {
+				errors.extend(changelog::run_vcs(&spec_dir, &repo_root)?);
+			}
+			run_one("changelog", result)
+		}
+		Some(CheckCommand::Whitespace { fix }) => {
+			run_one("whitespace", whitespace::run(&spec_dir, fix))
+		}
+		Some(CheckCommand::Version { vcs }) => {
+			let mut result = version::run(&spec_dir);
+			if vcs && let Ok(errors) = &mut result {
+				errors.extend(version::run_vcs(&spec_dir, &repo_root)?);
+			}
+			run_one("version", result)
+		}
 	}
 }
 
 fn run_all(spec_dir: &Path, repo_root: &Path) -> anyhow::Result<()> {
 	let checks: Vec<(&str, anyhow::Result<Vec<String>>)> = vec![
-		("links", links::run(spec_dir)),
+		("links", links::run(spec_dir, repo_root, false)),
+		("assets", assets::run(spec_dir, repo_root)),
 		("line-count", line_count::run(spec_dir, repo_root)),
-		("naming", naming::run(spec_dir)),
-		("forbidden", forbidden::run(spec_dir)),
+		("line-length", line_length::run(spec_dir)),
+		("naming", naming::run(spec_dir, repo_root)),
+		("forbidden", forbidden::run(spec_dir, repo_root)),
 		("terminology", terminology::run(spec_dir)),
+		("front-matter", front_matter::run(spec_dir, repo_root)),
+		("headings", headings::run(spec_dir)),
+		("structure", structure::run(spec_dir, false)),
+		("code-blocks", code_blocks::run(spec_dir)),
+		("requirements", requirements::run(spec_dir, repo_root)),
+		("rfc2119", rfc2119::run(spec_dir)),
+		("spelling", spelling::run(spec_dir, repo_root)),
+		("typography", typography::run(spec_dir)),
 		("changelog", changelog::run(spec_dir)),
+		("whitespace", whitespace::run(spec_dir, false)),
+		("version", version::run(spec_dir)),
 	];
 
 	let mut total_errors = 0;
@@ -114,25 +238,82 @@ pub fn find_spec_dir() -> anyhow::Result<PathBuf> {
 }
 
 pub fn collect_md_files(dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
-	let mut files = Vec::new();
-	walk_md(dir, &mut files)?;
-	files.sort();
+	let mut files = collect_files(dir)?;
+	files.retain(|f| f.extension().is_some_and(|ext| ext == "md"));
 	Ok(files)
 }
 
-fn walk_md(dir: &Path, files: &mut Vec<PathBuf>) -> anyhow::Result<()> {
-	let entries =
-		std::fs::read_dir(dir).with_context(|| format!("cannot read directory: {}", dir.display()))?;
-	for entry in entries {
-		let entry = entry?;
-		let path = entry.path();
-		if path.is_dir() {
-			walk_md(&path, files)?;
-		} else if path.extension().is_some_and(|ext| ext == "md") {
-			files.push(path);
+/// The markdown files minus CHANGELOG.md, whose released entries must not
+/// change, so checks that could demand edits to them skip it.
+pub fn collect_pages(dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+	let mut files = collect_md_files(dir)?;
+	files.retain(|f| f.file_name().is_none_or(|n| n != "CHANGELOG.md"));
+	Ok(files)
+}
+
+/// Files the checks cover: everything under the spec plus any repository
+/// path matching `[check] paths`, e.g. `**/README.md` or `docs/**`. Walking
+/// honors .gitignore, .ignore and `[fmt] ignore`.
+pub fn collect_files(spec_dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+	let ignore = config::load_fmt()?.ignore;
+	let mut files = walk::files(spec_dir, &ignore)?;
+
+	let paths = config::load_table("check")?
+		.and_then(|t| t.get_list("paths"))
+		.unwrap_or_default();
+	if !paths.is_empty()
+		&& let Some(repo_root) = spec_dir.parent()
+	{
+		for file in walk::files(repo_root, &ignore)? {
+			let rel = rel_path(&file, repo_root);
+			if !file.starts_with(spec_dir) && paths.iter().any(|g| glob_match(g, &rel)) {
+				files.push(file);
+			}
 		}
+		files.sort();
+	}
+	Ok(files)
+}
+
+/// `path` relative to `root` with forward slashes, as jj and qwq.toml spell it.
+pub fn rel_path(path: &Path, root: &Path) -> String {
+	path
+		.strip_prefix(root)
+		.unwrap_or(path)
+		.to_string_lossy()
+		.replace('\\', "/")
+}
+
+/// Match a repo-relative path against a glob: `*` and `?` stay within one
+/// path segment, `**` spans any number of segments.
+pub fn glob_match(pattern: &str, path: &str) -> bool {
+	let pattern: Vec<&str> = pattern.split('/').collect();
+	let path: Vec<&str> = path.split('/').collect();
+	match_segments(&pattern, &path)
+}
+
+fn match_segments(pattern: &[&str], path: &[&str]) -> bool {
+	match pattern.split_first() {
+		None => path.is_empty(),
+		Some((&"**", rest)) => (0..=path.len()).any(|i| match_segments(rest, &path[i..])),
+		Some((first, rest)) => match path.split_first() {
+			Some((name, path_rest)) => {
+				let p: Vec<char> = first.chars().collect();
+				let n: Vec<char> = name.chars().collect();
+				match_segment(&p, &n) && match_segments(rest, path_rest)
+			}
+			None => false,
+		},
+	}
+}
+
+fn match_segment(pattern: &[char], name: &[char]) -> bool {
+	match pattern.split_first() {
+		None => name.is_empty(),
+		Some(('*', rest)) => (0..=name.len()).any(|i| match_segment(rest, &name[i..])),
+		Some(('?', rest)) => !name.is_empty() && match_segment(rest, &name[1..]),
+		Some((c, rest)) => name.first() == Some(c) && match_segment(rest, &name[1..]),
 	}
-	Ok(())
 }
 
 pub fn is_in_code_block(line: &str, in_code_block: bool) -> bool {
@@ -141,3 +322,19 @@ pub fn is_in_code_block(line: &str, in_code_block: bool) -> bool {
 	}
 	in_code_block
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn glob_segments() {
+		assert!(glob_match("spec/**", "spec/a/b.md"));
+		assert!(glob_match("spec/**/*.md", "spec/b.md"));
+		assert!(glob_match("spec/*.md", "spec/b.md"));
+		assert!(!glob_match("spec/*.md", "spec/a/b.md"));
+		assert!(glob_match("**/README.md", "crates/x/README.md"));
+		assert!(glob_match("spec/v?.md", "spec/v1.md"));
+		assert!(!glob_match("spec/**", "docs/a.md"));
+	}
+}
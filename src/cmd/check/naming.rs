@@ -1,36 +1,285 @@
+use std::collections::HashMap;
 use std::path::Path;
 
-pub fn run(spec_dir: &Path) -> anyhow::Result<Vec<String>> {
+use anyhow::bail;
+use unicode_normalization::UnicodeNormalization;
+
+use super::{glob_match, rel_path, walk};
+use crate::config;
+
+const DEFAULT_ALLOW: &[&str] = &["CHANGELOG.md", "README.md", "VERSION"];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Style {
+	Kebab,
+	Snake,
+	Lower,
+	Camel,
+	Pascal,
+	ScreamingSnake,
+	Any,
+}
+
+impl Style {
+	fn parse(name: &str) -> anyhow::Result<Self> {
+		Ok(match name {
+			"kebab-case" => Style::Kebab,
+			"snake_case" => Style::Snake,
+			"lowercase" => Style::Lower,
+			"camelCase" => Style::Camel,
+			"PascalCase" => Style::Pascal,
+			"SCREAMING_SNAKE_CASE" => Style::ScreamingSnake,
+			"any" => Style::Any,
+			other => bail!(
+				"unknown naming style '{other}' (expected kebab-case, snake_case, lowercase, \
+				 camelCase, PascalCase, SCREAMING_SNAKE_CASE or any)"
+			),
+		})
+	}
+
+	fn label(self) -> &'static str {
+		match self {
+			Style::Kebab => "kebab-case",
+			Style::Snake => "snake_case",
+			Style::Lower => "lowercase",
+			Style::Camel => "camelCase",
+			Style::Pascal => "PascalCase",
+			Style::ScreamingSnake => "SCREAMING_SNAKE_CASE",
+			Style::Any => "any",
+		}
+	}
+
+	/// Whether a file or directory name follows the style. The style applies
+	/// to the part before the first `.`; what follows (extensions, version
+	/// suffixes) must be lowercase.
+	fn matches(self, name: &str) -> bool {
+		let (stem, rest) = name.split_once('.').unwrap_or((name, ""));
+		let lower_words = |sep: char| {
+			stem
+				.split(sep)
+				.all(|w| !w.is_empty() && w.chars().all(is_lower))
+		};
+		let stem_ok = match self {
+			Style::Any => return true,
+			Style::Kebab => lower_words('-'),
+			Style::Snake => lower_words('_'),
+			Style::Lower => !stem.is_empty() && stem.chars().all(is_lower),
+			Style::Camel => {
+				stem.starts_with(|c: char| c.is_ascii_lowercase())
+					&& stem.chars().all(|c| c.is_ascii_alphanumeric())
+			}
+			Style::Pascal => {
+				stem.starts_with(|c: char| c.is_ascii_uppercase())
+					&& stem.chars().all(|c| c.is_ascii_alphanumeric())
+			}
+			Style::ScreamingSnake => stem.split('_').all(|w| {
+				!w.is_empty()
+					&& w
+						.chars()
+						.all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+			}),
+		};
+		stem_ok
+			&& rest
+				.chars()
+				.all(|c| is_lower(c) || matches!(c, '.' | '-' | '_'))
+	}
+}
+
+fn is_lower(c: char) -> bool {
+	c.is_ascii_lowercase() || c.is_ascii_digit()
+}
+
+/// `[check.naming]` plus per-glob `[[check.naming.rule]]` styles.
+struct Policy {
+	/// Globs and the style their names follow, first match wins.
+	rules: Vec<(Vec<String>, Style)>,
+	/// Names (or name globs) exempt from every style, e.g. `README.md`.
+	allow: Vec<String>,
+	/// Most path segments below the repo root.
+	max_depth: Option<usize>,
+	/// Longest repo-relative path, in characters.
+	max_length: Option<usize>,
+	/// Whether rules came from qwq.toml; without any, only the spec is checked.
+	configured: bool,
+}
+
+impl Policy {
+	fn load() -> anyhow::Result<Self> {
+		let tables = config::load_tables()?;
+		let main = tables.iter().find(|t| t.name == "check.naming");
+
+		let mut rules = Vec::new();
+		for table in tables.iter().filter(|t| t.name == "check.naming.rule") {
+			let (Some(paths), Some(style)) = (table.get_list("paths"), table.get_str("style")) else {
+				bail!("[[check.naming.rule]] needs both `paths` and `style`");
+			};
+			rules.push((paths, Style::parse(&style)?));
+		}
+		let configured = !rules.is_empty();
+		if !configured {
+			rules.push((vec!["spec/**".to_string()], Style::Kebab));
+		}
+
+		Ok(Policy {
+			rules,
+			allow: main
+				.and_then(|t| t.get_list("allow"))
+				.unwrap_or_else(|| DEFAULT_ALLOW.iter().map(|s| s.to_string()).collect()),
+			max_depth: main.and_then(|t| t.get_usize("max_depth")),
+			max_length: main.and_then(|t| t.get_usize("max_length")),
+			configured,
+		})
+	}
+
+	fn style(&self, rel: &str) -> Option<Style> {
+		self
+			.rules
+			.iter()
+			.find(|(paths, _)| paths.iter().any(|g| glob_match(g, rel)))
+			.map(|(_, style)| *style)
+	}
+}
+
+/// Check names under the spec, or under every path a `[[check.naming.rule]]`
+/// covers when qwq.toml has any. Ignored files are skipped as in `walk`.
+pub fn run(spec_dir: &Path, repo_root: &Path) -> anyhow::Result<Vec<String>> {
+	let policy = Policy::load()?;
+	let ignore = config::load_fmt()?.ignore;
 	let mut errors = Vec::new();
-	walk_check(spec_dir, &mut errors)?;
+
+	let mut entries = if policy.configured {
+		walk::entries(repo_root, &ignore)?
+	} else {
+		let mut entries = walk::entries(spec_dir, &ignore)?;
+		entries.insert(0, spec_dir.to_path_buf());
+		entries
+	};
+	for path in &entries {
+		check_entry(path, repo_root, &policy, &mut errors);
+	}
+
+	// group siblings to compare names within each directory
+	entries.sort_by(|a, b| a.parent().cmp(&b.parent()).then_with(|| a.cmp(b)));
+	for siblings in entries.chunk_by(|a, b| a.parent() == b.parent()) {
+		let names: Vec<String> = siblings
+			.iter()
+			.map(|p| {
+				p.file_name()
+					.unwrap_or_default()
+					.to_string_lossy()
+					.into_owned()
+			})
+			.collect();
+		let dir = siblings[0].parent().unwrap_or(repo_root);
+		for (a, b, reason) in collisions(&names) {
+			errors.push(format!(
+				"NAME COLLISION: {} and {} (differ only in {reason})",
+				dir.join(a).display(),
+				dir.join(b).display()
+			));
+		}
+	}
 	Ok(errors)
 }
 
-fn is_allowed_uppercase(name: &str) -> bool {
-	matches!(name, "CHANGELOG.md" | "README.md" | "VERSION")
-}
+fn check_entry(path: &Path, repo_root: &Path, policy: &Policy, errors: &mut Vec<String>) {
+	let rel = rel_path(path, repo_root);
+	let name = path.file_name().unwrap_or_default().to_string_lossy();
 
-fn walk_check(dir: &Path, errors: &mut Vec<String>) -> anyhow::Result<()> {
-	for entry in std::fs::read_dir(dir)? {
-		let entry = entry?;
-		let path = entry.path();
-		let name = entry.file_name();
-		let name_str = name.to_string_lossy();
+	if let Some(style) = policy.style(&rel)
+		&& !policy.allow.iter().any(|g| glob_match(g, &name))
+		&& !style.matches(&name)
+	{
+		errors.push(format!(
+			"BAD NAME: {} (expected {})",
+			path.display(),
+			style.label()
+		));
+	}
 
-		if !is_allowed_uppercase(&name_str) {
-			let valid = name_str.starts_with(|c: char| c.is_ascii_lowercase() || c.is_ascii_digit())
-				&& name_str
-					.chars()
-					.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '.' || c == '-');
+	let depth = rel.split('/').count();
+	if let Some(max) = policy.max_depth
+		&& depth > max
+	{
+		errors.push(format!(
+			"PATH TOO DEEP: {} ({depth} levels, max {max})",
+			path.display()
+		));
+	}
+	let length = rel.chars().count();
+	if let Some(max) = policy.max_length
+		&& length > max
+	{
+		errors.push(format!(
+			"PATH TOO LONG: {} ({length} characters, max {max})",
+			path.display()
+		));
+	}
+}
 
-			if !valid {
-				errors.push(format!("BAD NAME: {}", path.display()));
+/// Pairs of names in one directory that a case-insensitive or normalizing
+/// filesystem would treat as the same file.
+fn collisions(names: &[String]) -> Vec<(&str, &str, &'static str)> {
+	let mut seen: HashMap<String, &str> = HashMap::new();
+	let mut found = Vec::new();
+	for name in names {
+		let key: String = name.nfkc().collect::<String>().to_lowercase();
+		match seen.get(&key) {
+			Some(first) => {
+				let reason = if first.to_lowercase() == name.to_lowercase() {
+					"case"
+				} else {
+					"Unicode normalization"
+				};
+				found.push((*first, name.as_str(), reason));
+			}
+			None => {
+				seen.insert(key, name);
 			}
 		}
+	}
+	found
+}
 
-		if path.is_dir() {
-			walk_check(&path, errors)?;
-		}
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn styles() {
+		assert!(Style::Kebab.matches("wire-format.md"));
+		assert!(Style::Kebab.matches("v1.2-notes.md"));
+		assert!(!Style::Kebab.matches("wire_format.md"));
+		assert!(!Style::Kebab.matches("Wire-Format.md"));
+		assert!(!Style::Kebab.matches("a--b.md"));
+		assert!(Style::Snake.matches("line_count.rs"));
+		assert!(!Style::Snake.matches("line-count.rs"));
+		assert!(Style::Pascal.matches("LineCount.tsx"));
+		assert!(!Style::Pascal.matches("LineCount.TSX"));
+		assert!(Style::Camel.matches("lineCount.ts"));
+		assert!(Style::ScreamingSnake.matches("VERSION"));
+		assert!(Style::Any.matches("Whatever Name.MD"));
+	}
+
+	#[test]
+	fn name_collisions() {
+		let names: Vec<String> = [
+			"Intro.md",
+			"intro.md",
+			"caf\u{e9}.md",
+			"cafe\u{301}.md",
+			"z.md",
+		]
+		.iter()
+		.map(|s| s.to_string())
+		.collect();
+		assert_eq!(
+			collisions(&names),
+			[
+				("Intro.md", "intro.md", "case"),
+				("caf\u{e9}.md", "cafe\u{301}.md", "Unicode normalization"),
+			]
+		);
 	}
-	Ok(())
 }
@@ -0,0 +1,172 @@
+use std::path::{Path, PathBuf};
+
+use super::{rel_path, walk};
+use crate::config;
+
+pub struct Mention {
+	pub id: String,
+	pub file: PathBuf,
+	pub line: usize,
+	/// `[REQ-x-001]` tags define a requirement; any other mention refers to one.
+	pub is_definition: bool,
+}
+
+pub fn run(spec_dir: &Path, repo_root: &Path) -> anyhow::Result<Vec<String>> {
+	let mut mentions = Vec::new();
+	for root in spec_roots(spec_dir, repo_root)? {
+		mentions.extend(scan_spec(&root)?);
+	}
+	let mut errors = Vec::new();
+
+	let definitions: Vec<&Mention> = mentions.iter().filter(|m| m.is_definition).collect();
+	for (i, def) in definitions.iter().enumerate() {
+		if let Some(first) = definitions[..i].iter().find(|d| d.id == def.id) {
+			errors.push(format!(
+				"REQ: {}:{} — {} already defined at {}:{}",
+				def.file.display(),
+				def.line,
+				def.id,
+				first.file.display(),
+				first.line
+			));
+		}
+	}
+
+	for reference in mentions.iter().filter(|m| !m.is_definition) {
+		if !definitions.iter().any(|d| d.id == reference.id) {
+			errors.push(format!(
+				"REQ: {}:{} — reference to undefined {}",
+				reference.file.display(),
+				reference.line,
+				reference.id
+			));
+		}
+	}
+
+	Ok(errors)
+}
+
+/// `spec/` plus any `[check.requirements] roots`, relative to the repository
+/// root; IDs must be unique across all of them.
+pub fn spec_roots(spec_dir: &Path, repo_root: &Path) -> anyhow::Result<Vec<PathBuf>> {
+	let mut roots = vec![spec_dir.to_path_buf()];
+	let configured = config::load_table("check.requirements")?.and_then(|t| t.get_list("roots"));
+	for root in configured.unwrap_or_default() {
+		let path = repo_root.join(&root);
+		if !path.is_dir() {
+			anyhow::bail!("[check.requirements] root '{root}' is not a directory");
+		}
+		if !roots
+			.iter()
+			.any(|r| rel_path(r, repo_root) == rel_path(&path, repo_root))
+		{
+			roots.push(path);
+		}
+	}
+	Ok(roots)
+}
+
+/// Every requirement ID mentioned in spec prose, outside code blocks.
+pub fn scan_spec(spec_dir: &Path) -> anyhow::Result<Vec<Mention>> {
+	let mut mentions = Vec::new();
+
+	let ignore = config::load_fmt()?.ignore;
+	let files = walk::files(spec_dir, &ignore)?;
+	for file in files
+		.iter()
+		.filter(|f| f.extension().is_some_and(|e| e == "md"))
+	{
+		let content = std::fs::read_to_string(file)?;
+		let mut in_code_block = false;
+
+		for (line_num, line) in content
+			.lines()
+			.enumerate()
+			.skip(super::front_matter::line_count(&content))
+		{
+			in_code_block = super::is_in_code_block(line, in_code_block);
+			if in_code_block || line.starts_with("```") {
+				continue;
+			}
+
+			for (id, is_definition) in find_ids(line) {
+				mentions.push(Mention {
+					id: id.to_string(),
+					file: file.clone(),
+					line: line_num + 1,
+					is_definition,
+				});
+			}
+		}
+	}
+
+	Ok(mentions)
+}
+
+/// Requirement IDs in `text`, flagged as definitions when written as a
+/// `[REQ-x-001]` tag that is not the text of a link.
+pub fn find_ids(text: &str) -> Vec<(&str, bool)> {
+	let mut ids = Vec::new();
+
+	for (start, _) in text.match_indices("REQ-") {
+		let before = &text[..start];
+		if before.ends_with(|c: char| c.is_alphanumeric() || c == '_' || c == '-') {
+			continue;
+		}
+		let Some(len) = id_len(&text[start..]) else {
+			continue;
+		};
+		let after = &text[start + len..];
+		let is_tag = before.ends_with('[') && after.starts_with(']') && !after.starts_with("](");
+		ids.push((&text[start..start + len], is_tag));
+	}
+
+	ids
+}
+
+/// Length of the `REQ-<area>-<number>` ID at the start of `text`, where the
+/// area is one or more lowercase segments and the number is all digits.
+fn id_len(text: &str) -> Option<usize> {
+	let mut len = "REQ".len();
+	let mut segments = Vec::new();
+
+	while let Some(rest) = text[len..].strip_prefix('-') {
+		let segment: &str = rest
+			.split(|c: char| !(c.is_ascii_lowercase() || c.is_ascii_digit()))
+			.next()
+			.unwrap_or_default();
+		if segment.is_empty() {
+			break;
+		}
+		segments.push(segment);
+		len += 1 + segment.len();
+	}
+
+	// a trailing alphanumeric means this was not a whole ID
+	let rest = &text[len..];
+	let number = segments.pop()?;
+	let area_ok = !segments.is_empty() && segments[0].starts_with(|c: char| c.is_ascii_lowercase());
+	let ok = area_ok
+		&& number.chars().all(|c| c.is_ascii_digit())
+		&& !rest.starts_with(|c: char| c.is_alphanumeric() || c == '_');
+	ok.then_some(len)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn ids() {
+		assert_eq!(
+			find_ids("[REQ-net-004] Clients MUST retry (see REQ-net-001)."),
+			[("REQ-net-004", true), ("REQ-net-001", false)]
+		);
+		assert_eq!(find_ids("[REQ-net-004](net.md)"), [("REQ-net-004", false)]);
+		assert_eq!(
+			find_ids("REQ-tcp-ip-12 and XREQ-a-1"),
+			[("REQ-tcp-ip-12", false)]
+		);
+		assert!(find_ids("REQ-net and REQ-004 and REQ-net-1a").is_empty());
+	}
+}
@@ -0,0 +1,229 @@
+use std::path::Path;
+
+use super::collect_md_files;
+use super::terminology::{contains_term, strip_inline_code};
+use crate::config;
+
+/// BCP 14 keywords, longest first so "MUST NOT" wins over "MUST".
+const KEYWORDS: &[&str] = &[
+	"NOT RECOMMENDED",
+	"MUST NOT",
+	"SHALL NOT",
+	"SHOULD NOT",
+	"RECOMMENDED",
+	"REQUIRED",
+	"OPTIONAL",
+	"SHOULD",
+	"SHALL",
+	"MUST",
+	"MAY",
+];
+
+const LOWERCASE: &[&str] = &["must", "should"];
+
+/// Sections whose heading contains one of these as whole words are
+/// informative by default. The page title (H1) never is.
+const INFORMATIVE: &[&str] = &[
+	"introduction",
+	"overview",
+	"background",
+	"rationale",
+	"example",
+	"examples",
+	"non-normative",
+	"informative",
+	"appendix",
+];
+
+pub fn run(spec_dir: &Path) -> anyhow::Result<Vec<String>> {
+	let informative = load_informative()?;
+	let mut errors = Vec::new();
+
+	for file in normative_files(spec_dir)? {
+		let content = std::fs::read_to_string(&file)?;
+		let scan = scan(&content, &informative);
+
+		for (line_num, word) in &scan.lowercase {
+			errors.push(format!(
+				"RFC2119: {}:{line_num} — lowercase \"{word}\" in normative text (use {} or rephrase)",
+				file.display(),
+				word.to_uppercase()
+			));
+		}
+
+		if !scan.statements.is_empty() && !has_boilerplate(&content) {
+			errors.push(format!(
+				"RFC2119: {} — uses requirement keywords without the RFC 2119/8174 boilerplate or a link to it",
+				file.display()
+			));
+		}
+	}
+
+	Ok(errors)
+}
+
+/// Print every normative statement per file, for review.
+pub fn report(spec_dir: &Path) -> anyhow::Result<()> {
+	let informative = load_informative()?;
+
+	for file in normative_files(spec_dir)? {
+		let content = std::fs::read_to_string(&file)?;
+		let scan = scan(&content, &informative);
+		if scan.statements.is_empty() {
+			continue;
+		}
+
+		println!("{}", file.display());
+		for (line_num, keywords, text) in &scan.statements {
+			println!("  {line_num}: [{}] {text}", keywords.join(", "));
+		}
+	}
+	Ok(())
+}
+
+fn normative_files(spec_dir: &Path) -> anyhow::Result<Vec<std::path::PathBuf>> {
+	let mut files = collect_md_files(spec_dir)?;
+	files.retain(|f| {
+		let name = f.file_name().unwrap_or_default().to_string_lossy();
+		name != "CHANGELOG.md" && name != "README.md"
+	});
+	Ok(files)
+}
+
+fn load_informative() -> anyhow::Result<Vec<String>> {
+	let configured = config::load_table("check.rfc2119")?.and_then(|t| t.get_list("informative"));
+	Ok(match configured {
+		Some(list) => list.iter().map(|s| s.to_lowercase()).collect(),
+		None => INFORMATIVE.iter().map(|s| s.to_string()).collect(),
+	})
+}
+
+struct Scan<'a> {
+	/// Line number, keywords used, and the line itself.
+	statements: Vec<(usize, Vec<&'static str>, &'a str)>,
+	/// Line number and lowercase keyword.
+	lowercase: Vec<(usize, &'static str)>,
+}
+
+fn scan<'a>(content: &'a str, informative: &[String]) -> Scan<'a> {
+	let mut result = Scan {
+		statements: Vec::new(),
+		lowercase: Vec::new(),
+	};
+	let mut in_code_block = false;
+	// heading level of the enclosing informative section, if any
+	let mut informative_level: Option<usize> = None;
+
+	for (line_num, line) in content
+		.lines()
+		.enumerate()
+		.skip(super::front_matter::line_count(content))
+	{
+		in_code_block = super::is_in_code_block(line, in_code_block);
+		if in_code_block || line.starts_with("```") {
+			continue;
+		}
+
+		let level = line.chars().take_while(|&c| c == '#').count();
+		if level > 0 && line[level..].starts_with(' ') {
+			if informative_level.is_some_and(|l| level <= l) {
+				informative_level = None;
+			}
+			let heading = line[level..].to_lowercase();
+			if level > 1
+				&& informative_level.is_none()
+				&& informative.iter().any(|i| contains_term(&heading, i, true))
+			{
+				informative_level = Some(level);
+			}
+			continue;
+		}
+		// the boilerplate quotes every keyword without using any of them
+		if informative_level.is_some() || line.contains("BCP 14") {
+			continue;
+		}
+
+		let prose = strip_inline_code(line);
+		let mut keywords = Vec::new();
+		let mut rest = prose.clone();
+		for keyword in KEYWORDS {
+			if contains_term(&rest, keyword, true) {
+				keywords.push(*keyword);
+				rest = rest.replace(keyword, " ");
+			}
+		}
+		if !keywords.is_empty() {
+			result.statements.push((line_num + 1, keywords, line));
+		}
+
+		for word in LOWERCASE {
+			if contains_term(&prose, word, true) {
+				result.lowercase.push((line_num + 1, word));
+			}
+		}
+	}
+
+	result
+}
+
+/// The BCP 14 boilerplate paragraph, or a link to RFC 2119 or RFC 8174.
+fn has_boilerplate(content: &str) -> bool {
+	let lower = content.to_lowercase();
+	(content.contains("BCP 14") && content.contains("RFC 2119"))
+		|| lower.contains("/rfc2119")
+		|| lower.contains("/rfc8174")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn defaults() -> Vec<String> {
+		INFORMATIVE.iter().map(|s| s.to_string()).collect()
+	}
+
+	#[test]
+	fn keywords_and_lowercase() {
+		let content = "# Spec\n\nClients MUST NOT retry and MAY log.\n\nServers must reply.\n";
+		let scan = scan(content, &defaults());
+		assert_eq!(scan.statements.len(), 1);
+		assert_eq!(scan.statements[0].1, ["MUST NOT", "MAY"]);
+		assert_eq!(scan.lowercase, [(5, "must")]);
+	}
+
+	#[test]
+	fn informative_sections() {
+		let content = "# Spec\n\n## Examples\n\nYou should try.\n\n### More\n\nIt must work.\n\n## Rules\n\nIt must work.\n";
+		let scan = scan(content, &defaults());
+		assert_eq!(scan.lowercase, [(13, "must")]);
+	}
+
+	#[test]
+	fn page_title_is_not_a_section() {
+		let content = "# Network overview
+
+## Rules
+
+Clients should retry.
+";
+		assert_eq!(scan(content, &defaults()).lowercase, [(5, "should")]);
+		let content = "# Spec
+
+## Counterexample handling
+
+Clients must retry.
+";
+		assert_eq!(scan(content, &defaults()).lowercase, [(5, "must")]);
+	}
+
+	#[test]
+	fn boilerplate() {
+		assert!(has_boilerplate(
+			"The key words ... are to be interpreted as described in BCP 14 [RFC 2119] [RFC 8174]"
+		));
+		assert!(has_boilerplate(
+			"See [RFC 2119](https://www.rfc-editor.org/rfc/rfc2119)."
+		));
+		assert!(!has_boilerplate("Clients MUST retry."));
+	}
+}
@@ -0,0 +1,201 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+use super::collect_pages;
+use super::terminology::strip_inline_code;
+use crate::config;
+
+/// Lowercase English words, one per line, bundled into the binary. The `#`
+/// header records where the list comes from and its license.
+const WORDS: &str = include_str!("words.txt");
+
+const DEFAULT_DICTIONARY: &str = "spec/dictionary.txt";
+
+/// Spell-check every page but CHANGELOG.md, whose released entries cannot be
+/// corrected and whose items start with a `scope:`.
+pub fn run(spec_dir: &Path, repo_root: &Path) -> anyhow::Result<Vec<String>> {
+	let files = collect_pages(spec_dir)?;
+	let project_words = load_dictionary(&dictionary_path(repo_root)?)?;
+	let mut known: HashSet<&str> = bundled_words().collect();
+	known.extend(project_words.iter().map(String::as_str));
+	let mut errors = Vec::new();
+
+	for file in &files {
+		let content = std::fs::read_to_string(file)?;
+		let mut in_code_block = false;
+
+		for (line_num, line) in content
+			.lines()
+			.enumerate()
+			.skip(super::front_matter::line_count(&content))
+		{
+			in_code_block = super::is_in_code_block(line, in_code_block);
+			if in_code_block || line.starts_with("```") {
+				continue;
+			}
+
+			let mut reported: Vec<&str> = Vec::new();
+			let prose = strip_links_and_urls(&strip_inline_code(line));
+			for word in words(&prose) {
+				// the list spells contractions with a straight apostrophe
+				let lower = word.to_lowercase().replace('’', "'");
+				if known.contains(lower.as_str()) || reported.contains(&word) {
+					continue;
+				}
+				errors.push(format!(
+					"SPELLING: {}:{} — unknown word \"{word}\"",
+					file.display(),
+					line_num + 1
+				));
+				reported.push(word);
+			}
+		}
+	}
+
+	Ok(errors)
+}
+
+fn bundled_words() -> impl Iterator<Item = &'static str> {
+	WORDS.lines().filter(|l| !l.starts_with('#'))
+}
+
+/// Append words to the project dictionary, keeping it sorted and unique.
+pub fn add_words(repo_root: &Path, new_words: &[String]) -> anyhow::Result<()> {
+	let path = dictionary_path(repo_root)?;
+	let mut words = load_dictionary(&path)?;
+	let before = words.len();
+	words.extend(new_words.iter().map(|w| w.trim().to_lowercase()));
+	words.retain(|w| !w.is_empty());
+	words.sort();
+	words.dedup();
+
+	let mut content = words.join("\n");
+	content.push('\n');
+	std::fs::write(&path, content).with_context(|| format!("cannot write {}", path.display()))?;
+	eprintln!(
+		"added {} word(s) to {}",
+		words.len() - before,
+		path.display()
+	);
+	Ok(())
+}
+
+/// `[check.spelling] dictionary`, relative to the repository root.
+fn dictionary_path(repo_root: &Path) -> anyhow::Result<PathBuf> {
+	let configured = config::load_table("check.spelling")?.and_then(|t| t.get_str("dictionary"));
+	Ok(repo_root.join(configured.as_deref().unwrap_or(DEFAULT_DICTIONARY)))
+}
+
+fn load_dictionary(path: &Path) -> anyhow::Result<Vec<String>> {
+	if !path.exists() {
+		return Ok(Vec::new());
+	}
+	let content =
+		std::fs::read_to_string(path).with_context(|| format!("cannot read {}", path.display()))?;
+	Ok(
+		content
+			.lines()
+			.map(str::trim)
+			.filter(|l| !l.is_empty() && !l.starts_with('#'))
+			.map(str::to_lowercase)
+			.collect(),
+	)
+}
+
+/// Drop link targets, autolinks and bare URLs, keeping link text.
+fn strip_links_and_urls(prose: &str) -> String {
+	let mut result = String::new();
+	let mut rest = prose;
+
+	while !rest.is_empty() {
+		if let Some(after) = rest.strip_prefix("](") {
+			result.push(' ');
+			rest = after.find(')').map_or("", |end| &after[end + 1..]);
+		} else if let Some(after) = rest.strip_prefix('<')
+			&& let Some(end) = after.find('>')
+		{
+			result.push(' ');
+			rest = &after[end + 1..];
+		} else if rest.starts_with("http://")
+			|| rest.starts_with("https://")
+			|| rest.starts_with("www.")
+		{
+			result.push(' ');
+			rest = rest
+				.find(char::is_whitespace)
+				.map_or("", |end| &rest[end..]);
+		} else {
+			let c = rest.chars().next().unwrap_or(' ');
+			result.push(c);
+			rest = &rest[c.len_utf8()..];
+		}
+	}
+	result
+}
+
+/// Words worth checking: ASCII letters with inner apostrophes, skipping
+/// acronyms and identifiers (`JSON`, `HashMap`) and stripping possessive 's.
+fn words(prose: &str) -> impl Iterator<Item = &str> {
+	prose
+		.split(|c: char| !(c.is_alphanumeric() || c == '\'' || c == '’' || c == '_' || c == '-'))
+		.flat_map(|token| token.split('-'))
+		.map(|w| w.trim_matches(['\'', '’']))
+		.map(|w| {
+			w.strip_suffix("'s")
+				.or_else(|| w.strip_suffix("’s"))
+				.unwrap_or(w)
+		})
+		.filter(|w| {
+			w.len() > 1
+				&& w
+					.chars()
+					.all(|c| c.is_ascii_alphabetic() || c == '\'' || c == '’')
+				&& !w.chars().skip(1).any(|c| c.is_ascii_uppercase())
+		})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn skips_links_and_urls() {
+		let prose =
+			strip_links_and_urls("see [the guide](docs/gude.md) or https://exmple.com <http://x.y>");
+		assert_eq!(
+			words(&prose).collect::<Vec<_>>(),
+			["see", "the", "guide", "or"]
+		);
+	}
+
+	#[test]
+	fn word_filtering() {
+		let found: Vec<&str> = words("The JSON HashMap crate's snake_case v2 well-known").collect();
+		assert_eq!(found, ["The", "crate", "well", "known"]);
+	}
+
+	#[test]
+	fn bundled_list() {
+		let known: HashSet<&str> = bundled_words().collect();
+		assert!(known.contains("specification"));
+		assert!(!known.contains("recieve"));
+		assert!(!known.contains("feclearexcept"));
+		assert!(!known.iter().any(|w| w.starts_with('#')));
+	}
+
+	#[test]
+	fn contractions() {
+		let known: HashSet<&str> = bundled_words().collect();
+		let found: Vec<&str> = words("Don't panic: it isn’t, can't and doesn't.").collect();
+		assert_eq!(
+			found,
+			["Don't", "panic", "it", "isn’t", "can't", "and", "doesn't"]
+		);
+		for word in found {
+			let lower = word.to_lowercase().replace('’', "'");
+			assert!(known.contains(lower.as_str()), "{word}");
+		}
+	}
+}
@@ -0,0 +1,387 @@
+use std::path::Path;
+
+use anyhow::{Context, bail};
+
+use super::collect_pages;
+use crate::config;
+
+/// Check list structure on every page but CHANGELOG.md, whose released
+/// entries `--fix` must not rewrite.
+pub fn run(spec_dir: &Path, fix: bool) -> anyhow::Result<Vec<String>> {
+	let files = collect_pages(spec_dir)?;
+	let marker = load_marker()?;
+	let mut errors = Vec::new();
+
+	for file in &files {
+		let mut content = std::fs::read_to_string(file)?;
+
+		if fix {
+			let fixed = check(&content, marker).fixed;
+			if fixed != content {
+				std::fs::write(file, &fixed).with_context(|| format!("cannot write {}", file.display()))?;
+				eprintln!("fixed: {}", file.display());
+				content = fixed;
+			}
+		}
+
+		for (line, kind, msg) in check(&content, marker).findings {
+			errors.push(format!("{kind}: {}:{line} — {msg}", file.display()));
+		}
+	}
+
+	Ok(errors)
+}
+
+/// `[check.lists] marker`, the bullet every unordered item uses.
+fn load_marker() -> anyhow::Result<char> {
+	let table = config::load_table("check.lists")?;
+	let marker = table
+		.and_then(|t| t.get_str("marker"))
+		.unwrap_or_else(|| "-".to_string());
+	match marker.as_str() {
+		"-" => Ok('-'),
+		"*" => Ok('*'),
+		"+" => Ok('+'),
+		other => bail!("unknown list marker '{other}' in [check.lists] (expected: -, *, +)"),
+	}
+}
+
+struct Report {
+	/// Line number, kind and message.
+	findings: Vec<(usize, &'static str, String)>,
+	/// The content with marker style and numbering fixed.
+	fixed: String,
+}
+
+/// An open list item, innermost last.
+struct Level {
+	indent: usize,
+	/// Column where the item text starts; nested items go here.
+	content: usize,
+	/// Next expected number for ordered lists.
+	next: Option<u64>,
+	/// Whether every item is numbered `1.`; `None` until the second item.
+	lazy: Option<bool>,
+}
+
+fn check(content: &str, marker: char) -> Report {
+	let mut findings = Vec::new();
+	let mut fixed = String::with_capacity(content.len());
+	let mut in_code_block = false;
+	let mut stack: Vec<Level> = Vec::new();
+	let mut table: Option<Table> = None;
+	let front_matter = super::front_matter::line_count(content);
+
+	for (line_num, raw) in content.split_inclusive('\n').enumerate() {
+		let line_num = line_num + 1;
+		let line = raw.trim_end_matches(['\n', '\r']);
+		let ending = &raw[line.len()..];
+
+		in_code_block = super::is_in_code_block(line, in_code_block);
+		if in_code_block || line.starts_with("```") || line_num <= front_matter {
+			fixed.push_str(raw);
+			continue;
+		}
+
+		// tables: consecutive lines starting with a pipe
+		if line.trim_start().starts_with('|') {
+			let t = table.get_or_insert_with(|| Table::new(line_num));
+			findings.extend(t.row(line_num, line));
+			fixed.push_str(raw);
+			continue;
+		}
+		if let Some(t) = table.take() {
+			findings.extend(t.finish());
+		}
+
+		let Some(item) = parse_item(line) else {
+			let indent = line.len() - line.trim_start().len();
+			let continues = line.trim().is_empty() || stack.last().is_some_and(|l| indent >= l.content);
+			if !continues || line.starts_with('#') {
+				stack.clear();
+			}
+			fixed.push_str(raw);
+			continue;
+		};
+
+		// close deeper lists this item is not part of
+		while stack.last().is_some_and(|l| item.indent < l.indent) {
+			stack.pop();
+		}
+		let nested = match stack.last() {
+			Some(parent) if item.indent >= parent.content => {
+				if item.indent != parent.content {
+					findings.push((
+						line_num,
+						"LIST",
+						format!(
+							"nested item indented {} spaces, expected {}",
+							item.indent, parent.content
+						),
+					));
+				}
+				true
+			}
+			Some(sibling) if item.indent != sibling.indent => {
+				findings.push((
+					line_num,
+					"LIST",
+					format!(
+						"item indented {} spaces, expected {} (sibling) or {} (nested)",
+						item.indent, sibling.indent, sibling.content
+					),
+				));
+				false
+			}
+			Some(_) => false,
+			None => true,
+		};
+
+		let mut text = line.to_string();
+		let mut lazy = None;
+		let next = match item.number {
+			Some(n) => {
+				let expected = match stack.last() {
+					Some(sibling) if !nested => match sibling.lazy {
+						// "1." on every item is common markdown style
+						None if n == 1 && sibling.next == Some(2) => {
+							lazy = Some(true);
+							1
+						}
+						Some(true) => {
+							lazy = Some(true);
+							1
+						}
+						_ => {
+							lazy = Some(false);
+							sibling.next.unwrap_or(n)
+						}
+					},
+					_ => {
+						lazy = (n != 1).then_some(false);
+						n
+					}
+				};
+				if n != expected {
+					findings.push((
+						line_num,
+						"LIST",
+						format!("ordered item numbered {n}, expected {expected}"),
+					));
+					// keep the `.` or `)` after the original digits
+					text = format!(
+						"{}{expected}{}",
+						&line[..item.indent],
+						&line[item.content - 2..]
+					);
+				}
+				Some(expected + 1)
+			}
+			None => {
+				let used = line[item.indent..].chars().next().unwrap_or(marker);
+				if used != marker {
+					findings.push((line_num, "LIST", format!("bullet '{used}', use '{marker}'")));
+					text.replace_range(item.indent..item.indent + 1, &marker.to_string());
+				}
+				None
+			}
+		};
+
+		if !nested {
+			stack.pop();
+		}
+		stack.push(Level {
+			indent: item.indent,
+			content: item.content + text.len() - line.len(),
+			next,
+			lazy,
+		});
+		fixed.push_str(&text);
+		fixed.push_str(ending);
+	}
+
+	if let Some(t) = table {
+		findings.extend(t.finish());
+	}
+
+	Report { findings, fixed }
+}
+
+pub struct Item {
+	pub indent: usize,
+	pub content: usize,
+	pub number: Option<u64>,
+}
+
+/// `- text`, `* text`, `+ text`, `1. text` or `1) text`.
+pub fn parse_item(line: &str) -> Option<Item> {
+	let indent = line.len() - line.trim_start_matches(' ').len();
+	let rest = &line[indent..];
+
+	if let Some(after) = rest.strip_prefix(['-', '*', '+']) {
+		// "---" and "***" are thematic breaks, "**bold**" is not a list
+		if after.starts_with(' ')
+			&& !after
+				.trim()
+				.chars()
+				.all(|c| c == '-' || c == '*' || c == ' ')
+		{
+			return Some(Item {
+				indent,
+				content: indent + 2,
+				number: None,
+			});
+		}
+		return None;
+	}
+
+	let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+	if !(1..=9).contains(&digits) {
+		return None;
+	}
+	let after = &rest[digits..];
+	if !(after.starts_with(". ") || after.starts_with(") ")) {
+		return None;
+	}
+	Some(Item {
+		indent,
+		content: indent + digits + 2,
+		number: rest[..digits].parse().ok(),
+	})
+}
+
+struct Table {
+	start: usize,
+	columns: Option<usize>,
+	rows: Vec<(usize, usize)>,
+	has_delimiter: bool,
+}
+
+impl Table {
+	fn new(start: usize) -> Self {
+		Self {
+			start,
+			columns: None,
+			rows: Vec::new(),
+			has_delimiter: false,
+		}
+	}
+
+	fn row(&mut self, line_num: usize, line: &str) -> Option<(usize, &'static str, String)> {
+		let cells = split_cells(line);
+		if self.rows.is_empty() {
+			self.columns = Some(cells.len());
+		} else if self.rows.len() == 1 {
+			self.has_delimiter = is_delimiter_row(&cells);
+		}
+		self.rows.push((line_num, cells.len()));
+
+		let columns = self.columns.unwrap_or(cells.len());
+		(cells.len() != columns).then(|| {
+			(
+				line_num,
+				"TABLE",
+				format!("row has {} cells, header has {columns}", cells.len()),
+			)
+		})
+	}
+
+	fn finish(self) -> Option<(usize, &'static str, String)> {
+		(!self.has_delimiter).then(|| {
+			(
+				self.start,
+				"TABLE",
+				"missing delimiter row (| --- |) after the header".to_string(),
+			)
+		})
+	}
+}
+
+/// Cells of a table row, ignoring pipes escaped or inside inline code.
+fn split_cells(line: &str) -> Vec<String> {
+	let trimmed = line.trim();
+	let inner = trimmed.strip_prefix('|').unwrap_or(trimmed);
+	let mut cells = Vec::new();
+	let mut cell = String::new();
+	let mut in_code = false;
+	let mut escaped = false;
+
+	for c in inner.chars() {
+		match c {
+			_ if escaped => escaped = false,
+			'\\' => escaped = true,
+			'`' => in_code = !in_code,
+			'|' if !in_code => {
+				cells.push(std::mem::take(&mut cell).trim().to_string());
+				continue;
+			}
+			_ => {}
+		}
+		cell.push(c);
+	}
+	if !cell.trim().is_empty() {
+		cells.push(cell.trim().to_string());
+	}
+	cells
+}
+
+fn is_delimiter_row(cells: &[String]) -> bool {
+	!cells.is_empty()
+		&& cells.iter().all(|c| {
+			let dashes = c.trim_start_matches(':').trim_end_matches(':');
+			!dashes.is_empty() && dashes.chars().all(|d| d == '-')
+		})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn findings(content: &str) -> Vec<(usize, &'static str, String)> {
+		check(content, '-').findings
+	}
+
+	#[test]
+	fn tables() {
+		assert!(findings("| a | b |\n| --- | :-: |\n| 1 | `x|y` |\n").is_empty());
+		assert_eq!(findings("| a | b |\n| --- | --- |\n| 1 |\n").len(), 1);
+		assert_eq!(
+			findings("| a | b |\n| 1 | 2 |\n"),
+			[(
+				1,
+				"TABLE",
+				"missing delimiter row (| --- |) after the header".to_string()
+			)]
+		);
+	}
+
+	#[test]
+	fn list_fixes() {
+		let report = check("* a\n  * b\n\n1. x\n3. y\n   1. z\n   3. w\n", '-');
+		assert_eq!(report.findings.len(), 4);
+		assert_eq!(report.fixed, "- a\n  - b\n\n1. x\n2. y\n   1. z\n   2. w\n");
+		assert!(findings(&report.fixed).is_empty());
+	}
+
+	#[test]
+	fn renumbering_keeps_text() {
+		let report = check("1. a\n2. b\n01. c\n", '-');
+		assert_eq!(report.fixed, "1. a\n2. b\n3. c\n");
+		let report = check("10. a\n9) b\n", '-');
+		assert_eq!(report.fixed, "10. a\n11) b\n");
+	}
+
+	#[test]
+	fn all_ones() {
+		assert!(findings("1. a\n1. b\n1. c\n").is_empty());
+		assert_eq!(findings("1. a\n1. b\n3. c\n").len(), 1);
+		assert_eq!(findings("1. a\n2. b\n1. c\n").len(), 1);
+	}
+
+	#[test]
+	fn list_indentation() {
+		assert_eq!(findings("- a\n    - b\n").len(), 1);
+		assert_eq!(findings("1. a\n  - b\n").len(), 1);
+		assert!(findings("1. a\n   - b\n- c\n\n---\n\n**bold** text\n").is_empty());
+	}
+}
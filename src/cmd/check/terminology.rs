@@ -1,16 +1,38 @@
 use std::path::Path;
 
+use anyhow::bail;
+
 use super::collect_md_files;
+use crate::config;
 
 pub fn run(spec_dir: &Path) -> anyhow::Result<Vec<String>> {
 	let files = collect_md_files(spec_dir)?;
-	let mut errors = Vec::new();
+	let glossary_path = spec_dir.join(GLOSSARY);
+	let terms = load_terms(&glossary_path)?;
+	let mut errors = check_glossary(&glossary_path, &terms);
 
 	for file in &files {
+		if *file == glossary_path {
+			continue;
+		}
 		let content = std::fs::read_to_string(file)?;
+		errors.extend(
+			undefined_uses(&content, &terms)
+				.into_iter()
+				.map(|(line, term)| {
+					format!(
+						"GLOSSARY: {}:{line} — \"{term}\" is used before linking to its definition in {GLOSSARY}",
+						file.display()
+					)
+				}),
+		);
 		let mut in_code_block = false;
 
-		for (line_num, line) in content.lines().enumerate() {
+		for (line_num, line) in content
+			.lines()
+			.enumerate()
+			.skip(super::front_matter::line_count(&content))
+		{
 			in_code_block = super::is_in_code_block(line, in_code_block);
 			if in_code_block || line.starts_with("```") {
 				continue;
@@ -19,28 +41,254 @@ pub fn run(spec_dir: &Path) -> anyhow::Result<Vec<String>> {
 			let prose = strip_inline_code(line);
 			let lower = prose.to_lowercase();
 
-			if contains_word_pair(&lower, "lib", "crate") {
-				errors.push(format!(
-					"TERMINOLOGY: {}:{} — use \"library crate\" instead of \"lib crate\"",
-					file.display(),
-					line_num + 1
-				));
+			for term in &terms {
+				let text = if term.case_sensitive { &prose } else { &lower };
+				for variant in &term.forbidden {
+					let variant = if term.case_sensitive {
+						variant.clone()
+					} else {
+						variant.to_lowercase()
+					};
+					if contains_term(text, &variant, term.whole_word) {
+						errors.push(format!(
+							"TERMINOLOGY: {}:{} — use \"{}\" instead of \"{variant}\"",
+							file.display(),
+							line_num + 1,
+							term.preferred
+						));
+					}
+				}
+			}
+		}
+	}
+
+	Ok(errors)
+}
+
+const GLOSSARY: &str = "glossary.md";
+
+/// A preferred term and the variants that must not be used instead of it.
+struct Term {
+	preferred: String,
+	forbidden: Vec<String>,
+	case_sensitive: bool,
+	whole_word: bool,
+	/// Definition and line number, for terms that come from the glossary.
+	definition: Option<(String, usize)>,
+}
+
+impl Term {
+	fn new(preferred: &str, forbidden: &[&str]) -> Self {
+		Term {
+			preferred: preferred.to_string(),
+			forbidden: forbidden.iter().map(|s| s.to_string()).collect(),
+			case_sensitive: false,
+			whole_word: true,
+			definition: None,
+		}
+	}
+}
+
+/// Terms from spec/glossary.md plus `[[check.terminology]]` in qwq.toml, on
+/// top of the built-in crate terminology unless they redefine its terms.
+fn load_terms(glossary_path: &Path) -> anyhow::Result<Vec<Term>> {
+	let mut terms = Vec::new();
+
+	if glossary_path.exists() {
+		let content = std::fs::read_to_string(glossary_path)?;
+		terms.extend(parse_glossary(&content));
+	}
+
+	for table in config::load_tables()? {
+		if table.name != "check.terminology" {
+			continue;
+		}
+		let Some(preferred) = table.get_str("preferred") else {
+			bail!("each [[check.terminology]] entry needs a `preferred` term");
+		};
+		terms.push(Term {
+			preferred,
+			forbidden: table.get_list("forbidden").unwrap_or_default(),
+			case_sensitive: table.get_bool("case_sensitive").unwrap_or(false),
+			whole_word: table.get_bool("whole_word").unwrap_or(true),
+			definition: None,
+		});
+	}
+
+	for builtin in [
+		Term::new("library crate", &["lib crate"]),
+		Term::new("binary crate", &["bin crate"]),
+	] {
+		if !terms.iter().any(|t| t.preferred == builtin.preferred) {
+			terms.push(builtin);
+		}
+	}
+	Ok(terms)
+}
+
+/// Read the `| Term | Avoid | Definition |` table from the glossary. Column
+/// order follows the header row; `Avoid` holds comma-separated variants.
+fn parse_glossary(content: &str) -> Vec<Term> {
+	let mut terms = Vec::new();
+	let mut columns: Option<Vec<String>> = None;
+
+	for (line_num, line) in content.lines().enumerate() {
+		let trimmed = line.trim();
+		if !trimmed.starts_with('|') {
+			columns = None;
+			continue;
+		}
+		let cells: Vec<&str> = trimmed
+			.trim_matches('|')
+			.split('|')
+			.map(str::trim)
+			.collect();
+
+		let Some(header) = &columns else {
+			let lower: Vec<String> = cells.iter().map(|c| c.to_lowercase()).collect();
+			if lower.iter().any(|c| c == "term") {
+				columns = Some(lower);
 			}
+			continue;
+		};
+		if cells
+			.iter()
+			.all(|c| c.chars().all(|ch| matches!(ch, '-' | ':')))
+		{
+			// delimiter row
+			continue;
+		}
+
+		let cell = |name: &str| {
+			header
+				.iter()
+				.position(|h| h == name)
+				.and_then(|i| cells.get(i))
+				.map(|c| c.trim_matches(['*', '`', '_']).trim())
+				.unwrap_or("")
+		};
+		let preferred = cell("term");
+		if preferred.is_empty() {
+			continue;
+		}
+		let avoid = match cell("avoid") {
+			"" => cell("forbidden"),
+			avoid => avoid,
+		};
 
-			if contains_word_pair(&lower, "bin", "crate") {
+		terms.push(Term {
+			preferred: preferred.to_string(),
+			forbidden: avoid
+				.split(',')
+				.map(|v| v.trim().to_string())
+				.filter(|v| !v.is_empty())
+				.collect(),
+			case_sensitive: false,
+			whole_word: true,
+			definition: Some((cell("definition").to_string(), line_num + 1)),
+		});
+	}
+
+	terms
+}
+
+/// Every glossary term needs a definition, and a definition may only rely on
+/// terms that the glossary has already defined above it.
+fn check_glossary(path: &Path, terms: &[Term]) -> Vec<String> {
+	let mut errors = Vec::new();
+	let defined: Vec<(&Term, &str, usize)> = terms
+		.iter()
+		.filter_map(|t| t.definition.as_ref().map(|(d, l)| (t, d.as_str(), *l)))
+		.collect();
+
+	for (i, (term, definition, line)) in defined.iter().enumerate() {
+		if definition.is_empty() {
+			errors.push(format!(
+				"GLOSSARY: {}:{line} — \"{}\" has no definition",
+				path.display(),
+				term.preferred
+			));
+			continue;
+		}
+
+		let lower = definition.to_lowercase();
+		for (later, _, _) in &defined[i + 1..] {
+			if contains_term(&lower, &later.preferred.to_lowercase(), true) {
 				errors.push(format!(
-					"TERMINOLOGY: {}:{} — use \"binary crate\" instead of \"bin crate\"",
-					file.display(),
-					line_num + 1
+					"GLOSSARY: {}:{line} — definition of \"{}\" uses \"{}\" before it is defined",
+					path.display(),
+					term.preferred,
+					later.preferred
 				));
 			}
 		}
 	}
 
-	Ok(errors)
+	errors
 }
 
-fn strip_inline_code(line: &str) -> String {
+/// First use of each glossary term on a spec page that comes before any link
+/// to the term's glossary entry, as (line, term). A link counts when its text
+/// is the term or its anchor is the term's heading slug.
+fn undefined_uses<'a>(content: &str, terms: &'a [Term]) -> Vec<(usize, &'a str)> {
+	let glossary_terms: Vec<&Term> = terms.iter().filter(|t| t.definition.is_some()).collect();
+	let mut settled: Vec<&str> = Vec::new();
+	let mut findings = Vec::new();
+	let mut in_code_block = false;
+
+	for (line_num, line) in content
+		.lines()
+		.enumerate()
+		.skip(super::front_matter::line_count(content))
+	{
+		in_code_block = super::is_in_code_block(line, in_code_block);
+		if in_code_block || line.starts_with("```") || line.starts_with('#') {
+			continue;
+		}
+		let links = glossary_links(line);
+		let prose = strip_inline_code(line).to_lowercase();
+
+		for term in &glossary_terms {
+			let name = term.preferred.as_str();
+			if settled.contains(&name) {
+				continue;
+			}
+			let lower = name.to_lowercase();
+			if links
+				.iter()
+				.any(|(text, anchor)| *text == lower || *anchor == lower.replace(' ', "-"))
+			{
+				settled.push(name);
+			} else if contains_term(&prose, &lower, true) {
+				findings.push((line_num + 1, name));
+				settled.push(name);
+			}
+		}
+	}
+
+	findings
+}
+
+/// Lowercased text and anchor of every `[text](…glossary.md#anchor)` link.
+fn glossary_links(line: &str) -> Vec<(String, String)> {
+	let mut links = Vec::new();
+	let mut rest = line;
+	while let Some(mid) = rest.find("](") {
+		let text = rest[..mid].rsplit('[').next().unwrap_or_default();
+		let after = &rest[mid + 2..];
+		let Some(end) = after.find(')') else {
+			break;
+		};
+		let (path, anchor) = after[..end].split_once('#').unwrap_or((&after[..end], ""));
+		if path == GLOSSARY || path.ends_with(&format!("/{GLOSSARY}")) {
+			links.push((text.to_lowercase(), anchor.to_lowercase()));
+		}
+		rest = &after[end + 1..];
+	}
+	links
+}
+
+pub fn strip_inline_code(line: &str) -> String {
 	let mut result = String::new();
 	let mut in_code = false;
 	for c in line.chars() {
@@ -53,11 +301,16 @@ fn strip_inline_code(line: &str) -> String {
 	result
 }
 
-fn contains_word_pair(text: &str, first: &str, second: &str) -> bool {
-	let pattern = format!("{first} {second}");
-	for (i, _) in text.match_indices(&pattern) {
+pub(super) fn contains_term(text: &str, term: &str, whole_word: bool) -> bool {
+	if term.is_empty() {
+		return false;
+	}
+	if !whole_word {
+		return text.contains(term);
+	}
+	for (i, _) in text.match_indices(term) {
 		let before_ok = i == 0 || !text.as_bytes()[i - 1].is_ascii_alphanumeric();
-		let end = i + pattern.len();
+		let end = i + term.len();
 		let after_ok = end >= text.len() || !text.as_bytes()[end].is_ascii_alphanumeric();
 		if before_ok && after_ok {
 			return true;
@@ -65,3 +318,49 @@ fn contains_word_pair(text: &str, first: &str, second: &str) -> bool {
 	}
 	false
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn whole_words() {
+		assert!(contains_term("a lib crate here", "lib crate", true));
+		assert!(!contains_term("a glib crate", "lib crate", true));
+		assert!(!contains_term("a lib crates", "lib crate", true));
+		assert!(contains_term("a lib crates", "lib crate", false));
+	}
+
+	#[test]
+	fn glossary_table() {
+		let content = "# Glossary\n\n| Term | Definition | Avoid |\n|---|---|---|\n\
+		               | **workspace** | A set of crate packages. | work space, ws |\n\
+		               | crate | |  |\n";
+		let terms = parse_glossary(content);
+		assert_eq!(terms.len(), 2);
+		assert_eq!(terms[0].preferred, "workspace");
+		assert_eq!(terms[0].forbidden, ["work space", "ws"]);
+		assert_eq!(check_glossary(Path::new("g.md"), &terms).len(), 2);
+	}
+
+	#[test]
+	fn defined_before_use() {
+		let mut terms = parse_glossary(
+			"| Term | Definition |\n|---|---|\n| workspace | A set of packages. |\n\
+			 | lockfile | Pinned versions. |\n",
+		);
+		terms.push(Term::new("library crate", &["lib crate"]));
+
+		let page = "# Workspace layout\n\nEach workspace has a lockfile.\n\
+		            See [lockfile](glossary.md#lockfile).\n\
+		            A [workspace](../spec/glossary.md) again, and a library crate.\n";
+		assert_eq!(
+			undefined_uses(page, &terms),
+			[(3, "workspace"), (3, "lockfile")]
+		);
+
+		let linked = "The [workspace](glossary.md#workspace) holds a lockfile, see \
+		              [pinning](glossary.md#lockfile).\n";
+		assert!(undefined_uses(linked, &terms).is_empty());
+	}
+}
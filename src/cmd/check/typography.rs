@@ -0,0 +1,169 @@
+use std::path::Path;
+
+use super::collect_md_files;
+use super::terminology::strip_inline_code;
+
+pub fn run(spec_dir: &Path) -> anyhow::Result<Vec<String>> {
+	let files = collect_md_files(spec_dir)?;
+	let mut errors = Vec::new();
+
+	for file in &files {
+		let content = std::fs::read_to_string(file)?;
+		let mut in_code_block = false;
+
+		for (line_num, line) in content
+			.lines()
+			.enumerate()
+			.skip(super::front_matter::line_count(&content))
+		{
+			in_code_block = super::is_in_code_block(line, in_code_block);
+			if in_code_block || line.starts_with("```") {
+				continue;
+			}
+
+			for finding in check_prose(&strip_inline_code(line)) {
+				errors.push(format!(
+					"TYPOGRAPHY: {}:{} — {finding}",
+					file.display(),
+					line_num + 1
+				));
+			}
+		}
+	}
+
+	Ok(errors)
+}
+
+fn check_prose(prose: &str) -> Vec<String> {
+	let chars: Vec<char> = prose.chars().collect();
+	let mut findings = Vec::new();
+
+	for (i, &c) in chars.iter().enumerate() {
+		let prev = i.checked_sub(1).map(|p| chars[p]);
+
+		// 中文English -> 中文 English
+		if let Some(p) = prev
+			&& ((is_cjk(p) && c.is_ascii_alphanumeric()) || (p.is_ascii_alphanumeric() && is_cjk(c)))
+		{
+			let (before, after) = split_context(&chars, i);
+			findings.push(format!(
+				"missing space between CJK and Latin: \"{before}{after}\" -> \"{before} {after}\""
+			));
+		}
+
+		// hello，world -> hello, world, but 我们使用 Rust。 is Chinese
+		if let Some(ascii) = halfwidth_of(c) {
+			let before = chars[..i].iter().rev().find(|c| !c.is_whitespace());
+			let after = chars[i + 1..].iter().find(|c| !c.is_whitespace());
+			let latin = |c: Option<&char>| c.is_some_and(|c| c.is_ascii_alphanumeric());
+			let cjk = |c: Option<&char>| c.is_some_and(|&c| is_cjk(c) || halfwidth_of(c).is_some());
+			let sentence = chars[..i]
+				.iter()
+				.rev()
+				.take_while(|&&c| !matches!(c, '.' | '!' | '?' | '。' | '！' | '？'));
+			if latin(before) && !cjk(after) && !sentence.copied().any(is_cjk) {
+				findings.push(format!("full-width '{c}' in English text, use '{ascii}'"));
+			}
+		}
+
+		// 中文,中文 -> 中文，中文
+		if let Some(full) = fullwidth_of(c)
+			&& prev.is_some_and(is_cjk)
+			&& chars
+				.get(i + 1)
+				.is_none_or(|n| n.is_whitespace() || is_cjk(*n))
+		{
+			findings.push(format!("half-width '{c}' in CJK text, use '{full}'"));
+		}
+	}
+
+	let styles = [
+		("\"", prose.contains('"')),
+		("“”", prose.contains(['“', '”'])),
+		("「」", prose.contains(['「', '」'])),
+	];
+	let used: Vec<&str> = styles.iter().filter(|(_, u)| *u).map(|(s, _)| *s).collect();
+	if used.len() > 1 {
+		findings.push(format!("mixed quote styles: {}", used.join(" and ")));
+	}
+
+	findings
+}
+
+/// Up to two characters on either side of position `i`, for suggestions.
+fn split_context(chars: &[char], i: usize) -> (String, String) {
+	let start = i.saturating_sub(2);
+	let end = (i + 2).min(chars.len());
+	let keep = |c: &&char| !c.is_whitespace();
+	let before: String = chars[start..i].iter().filter(keep).collect();
+	let after: String = chars[i..end].iter().filter(keep).collect();
+	(before, after)
+}
+
+fn is_cjk(c: char) -> bool {
+	matches!(c,
+		'\u{3040}'..='\u{30FF}'     // Hiragana, Katakana
+		| '\u{3400}'..='\u{4DBF}'   // CJK Extension A
+		| '\u{4E00}'..='\u{9FFF}'   // CJK Unified Ideographs
+		| '\u{AC00}'..='\u{D7AF}'   // Hangul Syllables
+		| '\u{F900}'..='\u{FAFF}'   // CJK Compatibility Ideographs
+		| '\u{20000}'..='\u{3134F}' // CJK Extensions B-G
+	)
+}
+
+fn halfwidth_of(c: char) -> Option<char> {
+	Some(match c {
+		'，' | '、' => ',',
+		'。' => '.',
+		'：' => ':',
+		'；' => ';',
+		'！' => '!',
+		'？' => '?',
+		'（' => '(',
+		'）' => ')',
+		_ => return None,
+	})
+}
+
+fn fullwidth_of(c: char) -> Option<char> {
+	Some(match c {
+		',' => '，',
+		'.' => '。',
+		':' => '：',
+		';' => '；',
+		'!' => '！',
+		'?' => '？',
+		_ => return None,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn spacing() {
+		assert_eq!(check_prose("使用Rust编写").len(), 2);
+		assert!(check_prose("使用 Rust 编写").is_empty());
+		assert_eq!(check_prose("版本2").len(), 1);
+	}
+
+	#[test]
+	fn punctuation() {
+		assert_eq!(check_prose("hello，world").len(), 1);
+		assert_eq!(check_prose("你好,世界").len(), 1);
+		assert_eq!(check_prose("结束.").len(), 1);
+		assert!(check_prose("你好，世界。").is_empty());
+		assert!(check_prose("我们使用 Rust。").is_empty());
+		assert!(check_prose("版本是 2。").is_empty());
+		assert!(check_prose("使用 Rust，然后 Cargo。").is_empty());
+		assert_eq!(check_prose("结束。Use Rust。").len(), 1);
+		assert!(check_prose("Hello, world. Version 1.2").is_empty());
+	}
+
+	#[test]
+	fn quotes() {
+		assert_eq!(check_prose("say “hi” and \"bye\"").len(), 1);
+		assert!(check_prose("say “hi” and “bye”").is_empty());
+	}
+}
@@ -0,0 +1,301 @@
+use std::cmp::Ordering;
+use std::path::Path;
+
+use anyhow::bail;
+
+use crate::config;
+
+pub fn run(spec_dir: &Path) -> anyhow::Result<Vec<String>> {
+	let scheme = load_scheme()?;
+	let mut errors = Vec::new();
+
+	let raw = std::fs::read_to_string(spec_dir.join("VERSION"))?;
+	match parse_version_file(&raw) {
+		Some(version) if scheme.parse(version).is_some() => {}
+		Some(version) => errors.push(format!(
+			"VERSION: spec/VERSION holds '{version}', not a valid {} version",
+			scheme.name()
+		)),
+		None => errors.push("VERSION: spec/VERSION must hold exactly one non-empty line".to_string()),
+	}
+
+	Ok(errors)
+}
+
+/// Compare the spec against the `main` bookmark: spec changes require a
+/// VERSION bump, and a VERSION bump requires a new CHANGELOG entry.
+pub fn run_vcs(spec_dir: &Path, repo_root: &Path) -> anyhow::Result<Vec<String>> {
+	let scheme = load_scheme()?;
+	let mut errors = Vec::new();
+
+	let spec_rel = super::rel_path(spec_dir, repo_root);
+	let version_path = format!("{spec_rel}/VERSION");
+	let changelog_path = format!("{spec_rel}/CHANGELOG.md");
+
+	let changed = crate::jj::changed_files(repo_root, "main", "@")?;
+	let spec_changed = changed
+		.iter()
+		.any(|f| f.starts_with(&format!("{spec_rel}/")) && *f != version_path);
+	let version_changed = changed.contains(&version_path);
+
+	if spec_changed && !version_changed {
+		errors.push(format!(
+			"VERSION NOT BUMPED: files under {spec_rel}/ changed since main but {version_path} did not"
+		));
+	}
+
+	if !version_changed {
+		return Ok(errors);
+	}
+
+	let new_raw = std::fs::read_to_string(spec_dir.join("VERSION"))?;
+	let old_raw = crate::jj::file_at(repo_root, "main", &version_path)?;
+	let new = parse_version_file(&new_raw).and_then(|v| scheme.parse(v));
+	let old = old_raw
+		.as_deref()
+		.and_then(parse_version_file)
+		.and_then(|v| scheme.parse(v));
+
+	if let (Some(old), Some(new)) = (&old, &new)
+		&& new.cmp(old) != Ordering::Greater
+	{
+		errors.push(format!(
+			"VERSION NOT INCREASED: {version_path} went from {old} to {new}"
+		));
+	}
+
+	let old_changelog = crate::jj::file_at(repo_root, "main", &changelog_path)?.unwrap_or_default();
+	let new_changelog = std::fs::read_to_string(spec_dir.join("CHANGELOG.md")).unwrap_or_default();
+	let old_entries: Vec<&str> = entry_headers(&old_changelog).collect();
+	let has_new_entry = entry_headers(&new_changelog).any(|h| !old_entries.contains(&h));
+
+	if !has_new_entry {
+		errors.push(format!(
+			"MISSING CHANGELOG ENTRY: {version_path} was bumped but {changelog_path} has no new entry"
+		));
+	}
+
+	Ok(errors)
+}
+
+fn entry_headers(content: &str) -> impl Iterator<Item = &str> {
+	content.lines().filter(|l| l.starts_with("## ["))
+}
+
+/// The VERSION file is a single line, optionally followed by a newline.
+fn parse_version_file(raw: &str) -> Option<&str> {
+	let version = raw.strip_suffix('\n').unwrap_or(raw);
+	if version.is_empty() || version.contains('\n') || version.trim() != version {
+		return None;
+	}
+	Some(version)
+}
+
+#[derive(Clone, Copy)]
+enum Scheme {
+	/// MAJOR.MINOR.PATCH[-PRE][+BUILD]
+	Semver,
+	/// YYYY.MM.DD[.N]
+	Calver,
+}
+
+impl Scheme {
+	fn name(self) -> &'static str {
+		match self {
+			Scheme::Semver => "semver",
+			Scheme::Calver => "calver",
+		}
+	}
+
+	fn parse(self, s: &str) -> Option<Version> {
+		match self {
+			Scheme::Semver => parse_semver(s),
+			Scheme::Calver => parse_calver(s),
+		}
+	}
+}
+
+fn load_scheme() -> anyhow::Result<Scheme> {
+	let table = config::load_table("check.version")?;
+	let name = table
+		.and_then(|t| t.get_str("scheme"))
+		.unwrap_or_else(|| "semver".to_string());
+	match name.as_str() {
+		"semver" => Ok(Scheme::Semver),
+		"calver" => Ok(Scheme::Calver),
+		other => {
+			bail!("unknown version scheme '{other}' in [check.version] (expected: semver, calver)")
+		}
+	}
+}
+
+#[derive(PartialEq, Eq)]
+struct Version {
+	raw: String,
+	parts: Vec<u64>,
+	pre: Vec<String>,
+}
+
+impl Ord for Version {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self
+			.parts
+			.cmp(&other.parts)
+			.then_with(|| cmp_pre(&self.pre, &other.pre))
+	}
+}
+
+impl PartialOrd for Version {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl std::fmt::Display for Version {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(&self.raw)
+	}
+}
+
+/// Semver precedence: a release ranks above any of its pre-releases, and
+/// pre-release identifiers compare numerically when both are numeric.
+fn cmp_pre(a: &[String], b: &[String]) -> Ordering {
+	match (a.is_empty(), b.is_empty()) {
+		(true, true) => return Ordering::Equal,
+		(true, false) => return Ordering::Greater,
+		(false, true) => return Ordering::Less,
+		(false, false) => {}
+	}
+	for (x, y) in a.iter().zip(b) {
+		let ord = match (x.parse::<u64>(), y.parse::<u64>()) {
+			(Ok(x), Ok(y)) => x.cmp(&y),
+			(Ok(_), Err(_)) => Ordering::Less,
+			(Err(_), Ok(_)) => Ordering::Greater,
+			(Err(_), Err(_)) => x.cmp(y),
+		};
+		if ord != Ordering::Equal {
+			return ord;
+		}
+	}
+	a.len().cmp(&b.len())
+}
+
+fn parse_semver(s: &str) -> Option<Version> {
+	let (rest, build) = match s.split_once('+') {
+		Some((rest, build)) => (rest, Some(build)),
+		None => (s, None),
+	};
+	let (core, pre) = match rest.split_once('-') {
+		Some((core, pre)) => (core, Some(pre)),
+		None => (rest, None),
+	};
+
+	let parts: Vec<&str> = core.split('.').collect();
+	if parts.len() != 3 || !parts.iter().all(|p| is_numeric_ident(p)) {
+		return None;
+	}
+
+	let is_ident =
+		|id: &str| !id.is_empty() && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+	let pre: Vec<String> = match pre {
+		Some(pre) => {
+			let ids: Vec<&str> = pre.split('.').collect();
+			let valid = ids.iter().all(|id| {
+				is_ident(id) && (!id.chars().all(|c| c.is_ascii_digit()) || is_numeric_ident(id))
+			});
+			if !valid {
+				return None;
+			}
+			ids.iter().map(|id| id.to_string()).collect()
+		}
+		None => Vec::new(),
+	};
+	if let Some(build) = build
+		&& !build.split('.').all(is_ident)
+	{
+		return None;
+	}
+
+	Some(Version {
+		raw: s.to_string(),
+		parts: parts.iter().filter_map(|p| p.parse().ok()).collect(),
+		pre,
+	})
+}
+
+/// Digits only, no leading zero unless the number is zero itself.
+fn is_numeric_ident(s: &str) -> bool {
+	!s.is_empty() && s.chars().all(|c| c.is_ascii_digit()) && (s == "0" || !s.starts_with('0'))
+}
+
+fn parse_calver(s: &str) -> Option<Version> {
+	let parts: Vec<&str> = s.split('.').collect();
+	if !(3..=4).contains(&parts.len()) || !parts.iter().all(|p| p.chars().all(|c| c.is_ascii_digit()))
+	{
+		return None;
+	}
+	let widths_ok = parts[0].len() == 4
+		&& parts[1].len() == 2
+		&& parts[2].len() == 2
+		&& parts.get(3).is_none_or(|p| is_numeric_ident(p));
+	if !widths_ok {
+		return None;
+	}
+
+	let nums: Vec<u64> = parts.iter().filter_map(|p| p.parse().ok()).collect();
+	if !(1..=12).contains(&nums[1]) || !(1..=31).contains(&nums[2]) {
+		return None;
+	}
+
+	Some(Version {
+		raw: s.to_string(),
+		parts: nums,
+		pre: Vec::new(),
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn semver_valid() {
+		assert!(parse_semver("0.1.0").is_some());
+		assert!(parse_semver("1.2.3-rc.1").is_some());
+		assert!(parse_semver("1.2.3-alpha+build.5").is_some());
+	}
+
+	#[test]
+	fn semver_invalid() {
+		assert!(parse_semver("1.2").is_none());
+		assert!(parse_semver("01.2.3").is_none());
+		assert!(parse_semver("v1.2.3").is_none());
+		assert!(parse_semver("1.2.3-").is_none());
+		assert!(parse_semver("1.2.3-01").is_none());
+	}
+
+	#[test]
+	fn semver_precedence() {
+		let cmp = |a, b| Some(parse_semver(a)?.cmp(&parse_semver(b)?));
+		assert_eq!(cmp("1.0.0", "1.0.0-rc.1"), Some(Ordering::Greater));
+		assert_eq!(cmp("1.0.0-rc.2", "1.0.0-rc.1"), Some(Ordering::Greater));
+		assert_eq!(cmp("1.0.0-rc.10", "1.0.0-rc.2"), Some(Ordering::Greater));
+		assert_eq!(cmp("1.10.0", "1.9.9"), Some(Ordering::Greater));
+	}
+
+	#[test]
+	fn calver() {
+		assert!(parse_calver("2026.02.10").is_some());
+		assert!(parse_calver("2026.02.10.1").is_some());
+		assert!(parse_calver("2026.13.10").is_none());
+		assert!(parse_calver("26.02.10").is_none());
+	}
+
+	#[test]
+	fn version_file_lines() {
+		assert_eq!(parse_version_file("1.0.0\n"), Some("1.0.0"));
+		assert_eq!(parse_version_file("1.0.0\n\n"), None);
+		assert_eq!(parse_version_file(" 1.0.0"), None);
+		assert_eq!(parse_version_file(""), None);
+	}
+}
@@ -0,0 +1,186 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+use super::glob_match;
+
+/// Per-directory ignore files, read in this order so `.ignore` wins.
+const IGNORE_FILES: &[&str] = &[".gitignore", ".ignore"];
+
+/// One line of a `.gitignore` or `.ignore` file.
+#[derive(Debug, PartialEq)]
+struct Rule {
+	/// Directory holding the ignore file; the pattern is relative to it.
+	base: PathBuf,
+	/// Glob in `glob_match` syntax; unanchored patterns get a `**/` prefix.
+	pattern: String,
+	negate: bool,
+	dir_only: bool,
+}
+
+/// Every file and directory under `start`, sorted. Hidden directories, names
+/// in `ignore` (the `[fmt] ignore` list) and whatever .gitignore or .ignore
+/// files exclude are skipped, including ignore files in parent directories up
+/// to the enclosing git or jj checkout.
+pub fn entries(start: &Path, ignore: &[String]) -> anyhow::Result<Vec<PathBuf>> {
+	let top = start
+		.ancestors()
+		.find(|dir| dir.join(".git").exists() || dir.join(".jj").exists())
+		.unwrap_or(start);
+
+	let mut rules = Vec::new();
+	let parents: Vec<&Path> = start
+		.ancestors()
+		.skip(1)
+		.take_while(|dir| dir.starts_with(top))
+		.collect();
+	for dir in parents.into_iter().rev() {
+		rules.extend(load_rules(dir)?);
+	}
+
+	let mut found = Vec::new();
+	walk(start, ignore, &mut rules, &mut found)?;
+	found.sort();
+	Ok(found)
+}
+
+/// The files among `entries`.
+pub fn files(start: &Path, ignore: &[String]) -> anyhow::Result<Vec<PathBuf>> {
+	let mut files = entries(start, ignore)?;
+	files.retain(|path| !path.is_dir());
+	Ok(files)
+}
+
+fn walk(
+	dir: &Path,
+	ignore: &[String],
+	rules: &mut Vec<Rule>,
+	found: &mut Vec<PathBuf>,
+) -> anyhow::Result<()> {
+	let inherited = rules.len();
+	rules.extend(load_rules(dir)?);
+
+	let entries =
+		std::fs::read_dir(dir).with_context(|| format!("cannot read directory: {}", dir.display()))?;
+	for entry in entries {
+		let entry = entry?;
+		let path = entry.path();
+		let name = entry.file_name().to_string_lossy().into_owned();
+		let is_dir = path.is_dir();
+		if (is_dir && name.starts_with('.'))
+			|| ignore.contains(&name)
+			|| is_ignored(rules, &path, is_dir)
+		{
+			continue;
+		}
+
+		found.push(path.clone());
+		if is_dir {
+			walk(&path, ignore, rules, found)?;
+		}
+	}
+
+	rules.truncate(inherited);
+	Ok(())
+}
+
+fn load_rules(dir: &Path) -> anyhow::Result<Vec<Rule>> {
+	let mut rules = Vec::new();
+	for name in IGNORE_FILES {
+		let path = dir.join(name);
+		if path.is_file() {
+			let content = std::fs::read_to_string(&path)
+				.with_context(|| format!("cannot read {}", path.display()))?;
+			rules.extend(parse_rules(dir, &content));
+		}
+	}
+	Ok(rules)
+}
+
+/// The gitignore subset qwq understands: comments, `!` negation, a trailing
+/// `/` for directories only, and patterns anchored by any inner or leading
+/// `/`. Character classes are not supported.
+fn parse_rules(base: &Path, content: &str) -> Vec<Rule> {
+	content
+		.lines()
+		.filter_map(|line| {
+			let line = line.trim_end();
+			if line.is_empty() || line.starts_with('#') {
+				return None;
+			}
+			let (negate, line) = match line.strip_prefix('!') {
+				Some(rest) => (true, rest),
+				None => (false, line),
+			};
+			let (dir_only, line) = match line.strip_suffix('/') {
+				Some(rest) => (true, rest),
+				None => (false, line),
+			};
+			let pattern = match line.strip_prefix('/') {
+				Some(anchored) => anchored.to_string(),
+				None if line.contains('/') => line.to_string(),
+				None => format!("**/{line}"),
+			};
+			Some(Rule {
+				base: base.to_path_buf(),
+				pattern,
+				negate,
+				dir_only,
+			})
+		})
+		.collect()
+}
+
+/// The last matching rule decides, so a later `!pattern` re-includes.
+fn is_ignored(rules: &[Rule], path: &Path, is_dir: bool) -> bool {
+	let mut ignored = false;
+	for rule in rules.iter().filter(|r| is_dir || !r.dir_only) {
+		let Ok(rel) = path.strip_prefix(&rule.base) else {
+			continue;
+		};
+		let rel = rel.to_string_lossy().replace('\\', "/");
+		if !rel.is_empty() && glob_match(&rule.pattern, &rel) {
+			ignored = !rule.negate;
+		}
+	}
+	ignored
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parsing() {
+		let base = Path::new("/repo");
+		let rules = parse_rules(base, "# build\n/target/\n*.log\n!keep.log\ndocs/gen\n\n");
+		let summary: Vec<(&str, bool, bool)> = rules
+			.iter()
+			.map(|r| (r.pattern.as_str(), r.negate, r.dir_only))
+			.collect();
+		assert_eq!(
+			summary,
+			[
+				("target", false, true),
+				("**/*.log", false, false),
+				("**/keep.log", true, false),
+				("docs/gen", false, false),
+			]
+		);
+	}
+
+	#[test]
+	fn matching() {
+		let mut rules = parse_rules(Path::new("/repo"), "/target/\n*.log\n!keep.log\n");
+		rules.extend(parse_rules(Path::new("/repo/spec"), "draft.md\n"));
+		let ignored = |path: &str, is_dir: bool| is_ignored(&rules, Path::new(path), is_dir);
+
+		assert!(ignored("/repo/target", true));
+		assert!(!ignored("/repo/target", false));
+		assert!(!ignored("/repo/crates/x/target", true));
+		assert!(ignored("/repo/spec/run.log", false));
+		assert!(!ignored("/repo/spec/keep.log", false));
+		assert!(ignored("/repo/spec/a/draft.md", false));
+		assert!(!ignored("/repo/draft.md", false));
+	}
+}
@@ -0,0 +1,299 @@
+use std::path::Path;
+
+use anyhow::Context;
+
+use super::collect_files;
+use crate::config;
+
+pub fn run(spec_dir: &Path, fix: bool) -> anyhow::Result<Vec<String>> {
+	let files = collect_files(spec_dir)?;
+	let tables = config::load_tables()?;
+	let mut errors = Vec::new();
+
+	for file in &files {
+		let bytes = std::fs::read(file)?;
+		if bytes.contains(&0) {
+			// binary file
+			continue;
+		}
+		let Ok(content) = String::from_utf8(bytes) else {
+			errors.push(format!("ENCODING: {} is not valid UTF-8", file.display()));
+			continue;
+		};
+
+		let ext = file
+			.extension()
+			.map(|e| e.to_string_lossy().into_owned())
+			.unwrap_or_default();
+		let policy = Policy::load(&tables, &ext);
+		let is_md = ext == "md";
+
+		if fix {
+			let fixed = policy.fix(&content, is_md);
+			if fixed != content {
+				std::fs::write(file, &fixed).with_context(|| format!("cannot write {}", file.display()))?;
+				eprintln!("fixed: {}", file.display());
+			}
+			errors.extend(policy.check(file, &fixed, is_md));
+		} else {
+			errors.extend(policy.check(file, &content, is_md));
+		}
+	}
+
+	Ok(errors)
+}
+
+/// Rules from `[check.whitespace]`, overridden per extension by
+/// `[check.whitespace.<ext>]`. Every rule is enforced by default except hard
+/// tabs outside markdown, where Makefiles and the like need them.
+struct Policy {
+	/// Trailing whitespace, except inside fenced code blocks and markdown
+	/// hard breaks (two trailing spaces).
+	trailing_whitespace: bool,
+	/// Hard tabs outside fenced code blocks.
+	hard_tabs: bool,
+	crlf: bool,
+	final_newline: bool,
+	bom: bool,
+	max_blank_lines: usize,
+	/// Columns per tab stop when `--fix` expands tabs.
+	tab_width: usize,
+}
+
+impl Default for Policy {
+	fn default() -> Self {
+		Self {
+			trailing_whitespace: true,
+			hard_tabs: true,
+			crlf: true,
+			final_newline: true,
+			bom: true,
+			max_blank_lines: 1,
+			tab_width: 4,
+		}
+	}
+}
+
+impl Policy {
+	fn load(tables: &[config::Table], ext: &str) -> Self {
+		let mut policy = Policy {
+			hard_tabs: ext == "md",
+			..Policy::default()
+		};
+
+		let specific = format!("check.whitespace.{ext}");
+		let layers = tables
+			.iter()
+			.filter(|t| t.name == "check.whitespace")
+			.chain(
+				tables
+					.iter()
+					.filter(|t| !ext.is_empty() && t.name == specific),
+			);
+		for table in layers {
+			let flag = |key: &str, current: bool| table.get_bool(key).unwrap_or(current);
+			policy.trailing_whitespace = flag("trailing_whitespace", policy.trailing_whitespace);
+			policy.hard_tabs = flag("hard_tabs", policy.hard_tabs);
+			policy.crlf = flag("crlf", policy.crlf);
+			policy.final_newline = flag("final_newline", policy.final_newline);
+			policy.bom = flag("bom", policy.bom);
+			policy.max_blank_lines = table
+				.get_usize("max_blank_lines")
+				.unwrap_or(policy.max_blank_lines);
+			policy.tab_width = table.get_usize("tab_width").unwrap_or(policy.tab_width);
+		}
+		policy
+	}
+
+	fn check(&self, file: &Path, content: &str, is_md: bool) -> Vec<String> {
+		let mut errors = Vec::new();
+		let path = file.display();
+
+		if self.bom && content.starts_with('\u{FEFF}') {
+			errors.push(format!("ENCODING: {path} starts with a byte order mark"));
+		}
+		if self.crlf && content.contains('\r') {
+			errors.push(format!("ENCODING: {path} uses CRLF line endings"));
+		}
+		if self.final_newline && !content.is_empty() && !content.ends_with('\n') {
+			errors.push(format!("WHITESPACE: {path} is missing a final newline"));
+		}
+
+		let mut in_code_block = false;
+		let mut blank_run = 0;
+		let lines: Vec<&str> = content.lines().collect();
+		for (line_num, &line) in lines.iter().enumerate() {
+			let fence = is_md && line.starts_with("```");
+			in_code_block = is_md && super::is_in_code_block(line, in_code_block);
+			let keep_trailing = (in_code_block && !fence)
+				|| (is_md && is_hard_break(line, lines.get(line_num + 1).copied()));
+
+			if self.trailing_whitespace && !keep_trailing && line.ends_with([' ', '\t']) {
+				errors.push(format!(
+					"WHITESPACE: {path}:{} — trailing whitespace",
+					line_num + 1
+				));
+			}
+			if self.hard_tabs && !in_code_block && !fence && line.contains('\t') {
+				errors.push(format!("WHITESPACE: {path}:{} — hard tab", line_num + 1));
+			}
+
+			if line.trim().is_empty() && !in_code_block {
+				blank_run += 1;
+				if blank_run == self.max_blank_lines + 1 {
+					errors.push(format!(
+						"WHITESPACE: {path}:{} — more than {} consecutive blank line(s)",
+						line_num + 1,
+						self.max_blank_lines
+					));
+				}
+			} else {
+				blank_run = 0;
+			}
+		}
+
+		errors
+	}
+
+	fn fix(&self, content: &str, is_md: bool) -> String {
+		let mut body = content;
+		if self.bom {
+			body = body.strip_prefix('\u{FEFF}').unwrap_or(body);
+		}
+		if body.is_empty() {
+			return String::new();
+		}
+		let had_newline = body.ends_with('\n');
+		let body = body.strip_suffix('\n').unwrap_or(body);
+
+		let mut lines: Vec<String> = Vec::new();
+		let mut in_code_block = false;
+		let mut blank_run = 0;
+		let raw_lines: Vec<&str> = body.split('\n').collect();
+		for (i, raw) in raw_lines.iter().enumerate() {
+			let (text, cr) = match raw.strip_suffix('\r') {
+				Some(text) => (text, !self.crlf),
+				None => (*raw, false),
+			};
+			let mut line = text.to_string();
+			let fence = is_md && line.starts_with("```");
+			in_code_block = is_md && super::is_in_code_block(&line, in_code_block);
+			let next = raw_lines.get(i + 1).map(|n| n.trim_end_matches('\r'));
+			let keep_trailing = (in_code_block && !fence) || (is_md && is_hard_break(&line, next));
+
+			if self.trailing_whitespace && !keep_trailing {
+				line.truncate(line.trim_end_matches([' ', '\t']).len());
+			}
+			if self.hard_tabs && !in_code_block && !fence {
+				line = expand_tabs(&line, self.tab_width);
+			}
+
+			if line.trim().is_empty() && !in_code_block {
+				blank_run += 1;
+				if blank_run > self.max_blank_lines {
+					continue;
+				}
+			} else {
+				blank_run = 0;
+			}
+			if cr {
+				line.push('\r');
+			}
+			lines.push(line);
+		}
+
+		if self.final_newline {
+			while lines.last().is_some_and(|l| l.trim().is_empty()) {
+				lines.pop();
+			}
+		}
+		let mut result = lines.join("\n");
+		if (self.final_newline || had_newline) && !result.is_empty() {
+			result.push('\n');
+		}
+		result
+	}
+}
+
+/// Two or more trailing spaces ending a line of a paragraph that continues
+/// on the next line: a markdown hard break, not stray whitespace.
+fn is_hard_break(line: &str, next: Option<&str>) -> bool {
+	line.ends_with("  ") && !line.trim().is_empty() && next.is_some_and(|n| !n.trim().is_empty())
+}
+
+fn expand_tabs(line: &str, width: usize) -> String {
+	let width = width.max(1);
+	let mut result = String::new();
+	let mut col = 0;
+	for c in line.chars() {
+		if c == '\t' {
+			let spaces = width - col % width;
+			result.push_str(&" ".repeat(spaces));
+			col += spaces;
+		} else {
+			result.push(c);
+			col += 1;
+		}
+	}
+	result
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn fix_everything() {
+		let policy = Policy::default();
+		let input = "\u{FEFF}# Title  \r\n\r\n\r\n\tbody\r\n\n\n";
+		assert_eq!(policy.fix(input, true), "# Title\n\n    body\n");
+	}
+
+	#[test]
+	fn fix_keeps_code_blocks() {
+		let policy = Policy::default();
+		let input = "```\n\tcode\n\n\n```\n";
+		assert_eq!(policy.fix(input, true), input);
+		let input = "```sh\necho 'a  '  \n```\n";
+		assert_eq!(policy.fix(input, true), input);
+		assert!(policy.check(Path::new("a.md"), input, true).is_empty());
+	}
+
+	#[test]
+	fn fix_keeps_hard_breaks() {
+		let policy = Policy::default();
+		let input = "first line  \nsecond line  \n\nend\n";
+		assert_eq!(
+			policy.fix(input, true),
+			"first line  \nsecond line\n\nend\n"
+		);
+		assert_eq!(policy.check(Path::new("a.md"), input, true).len(), 1);
+	}
+
+	#[test]
+	fn tabs_allowed_outside_markdown() {
+		let tables = config::parse_tables("").unwrap_or_default();
+		let make = Policy::load(&tables, "mk");
+		assert_eq!(
+			make.fix("all:\n\tcc -o a a.c\n", false),
+			"all:\n\tcc -o a a.c\n"
+		);
+		assert!(Policy::load(&tables, "md").hard_tabs);
+	}
+
+	#[test]
+	fn fix_respects_disabled_rules() {
+		let policy = Policy {
+			crlf: false,
+			final_newline: false,
+			..Policy::default()
+		};
+		assert_eq!(policy.fix("a\r\nb", false), "a\r\nb");
+	}
+
+	#[test]
+	fn tab_stops() {
+		assert_eq!(expand_tabs("\tx", 4), "    x");
+		assert_eq!(expand_tabs("ab\tx", 4), "ab  x");
+	}
+}
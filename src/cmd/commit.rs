@@ -17,6 +17,8 @@ const VALID_TYPES: &[&str] = &[
 
 pub fn execute(args: Args) -> anyhow::Result<()> {
 	validate_message(&args.m)?;
+	let subject = args.m.lines().next().unwrap_or("");
+	crate::cmd::changelog::require_documented(subject, "@-", "@", &args.files)?;
 
 	if args.files.is_empty() {
 		jj_commit(&args.m)
@@ -28,27 +30,7 @@ pub fn execute(args: Args) -> anyhow::Result<()> {
 fn validate_message(msg: &str) -> anyhow::Result<()> {
 	let subject = msg.lines().next().unwrap_or("");
 
-	// format: type(scope): lowercase description, no trailing period
-	let valid = match subject.split_once('(') {
-		Some((type_part, rest)) => {
-			let type_ok = VALID_TYPES.contains(&type_part);
-			match rest.split_once("): ") {
-				Some((scope, desc)) => {
-					let scope_ok = !scope.is_empty()
-						&& scope.starts_with(|c: char| c.is_ascii_lowercase())
-						&& scope
-							.chars()
-							.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
-					let desc_ok = desc.starts_with(|c: char| c.is_ascii_lowercase()) && !desc.ends_with('.');
-					type_ok && scope_ok && desc_ok
-				}
-				None => false,
-			}
-		}
-		None => false,
-	};
-
-	if !valid {
+	if parse_subject(subject).is_none() {
 		let types_str = VALID_TYPES.join(", ");
 		bail!(
 			"subject does not match type(scope): description format.\n  \
@@ -83,6 +65,34 @@ fn validate_message(msg: &str) -> anyhow::Result<()> {
 	Ok(())
 }
 
+pub struct Subject<'a> {
+	pub kind: &'a str,
+	pub scope: &'a str,
+	pub description: &'a str,
+}
+
+/// Split a `type(scope): description` subject, or `None` if it is malformed.
+pub fn parse_subject(subject: &str) -> Option<Subject<'_>> {
+	// format: type(scope): lowercase description, no trailing period
+	let (kind, rest) = subject.split_once('(')?;
+	let (scope, description) = rest.split_once("): ")?;
+
+	let type_ok = VALID_TYPES.contains(&kind);
+	let scope_ok = !scope.is_empty()
+		&& scope.starts_with(|c: char| c.is_ascii_lowercase())
+		&& scope
+			.chars()
+			.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+	let desc_ok =
+		description.starts_with(|c: char| c.is_ascii_lowercase()) && !description.ends_with('.');
+
+	(type_ok && scope_ok && desc_ok).then_some(Subject {
+		kind,
+		scope,
+		description,
+	})
+}
+
 fn jj_commit(msg: &str) -> anyhow::Result<()> {
 	let status = std::process::Command::new("jj")
 		.args(["commit", "-m", msg])
@@ -2,6 +2,7 @@ use std::path::{Path, PathBuf};
 
 use anyhow::Context;
 
+use crate::cmd::check::{find_spec_dir, line_length, walk};
 use crate::config;
 
 #[derive(clap::Args)]
@@ -28,6 +29,18 @@ pub fn execute(args: Args) -> anyhow::Result<()> {
 		failed = true;
 	}
 
+	// only pages the line-length check covers, so fmt and check agree
+	if let Some(policy) = line_length::load_policy()?
+		&& let Ok(spec_dir) = find_spec_dir()
+	{
+		let spec_dir = spec_dir.canonicalize().unwrap_or(spec_dir);
+		for file in line_length::files(&spec_dir)? {
+			if file.starts_with(&path) && !rewrap_md(&file, &policy, args.check)? {
+				failed = true;
+			}
+		}
+	}
+
 	if path.is_dir() {
 		if !run_oxfmt(&path, &cfg.oxfmt, args.check)? {
 			failed = true;
@@ -69,6 +82,23 @@ fn run_rustfmt(
 	Ok(status.success())
 }
 
+/// Rewrap markdown paragraphs per `[check.line_length]`; false when `check`
+/// finds a file that would change.
+fn rewrap_md(file: &Path, policy: &line_length::Policy, check: bool) -> anyhow::Result<bool> {
+	let content =
+		std::fs::read_to_string(file).with_context(|| format!("cannot read {}", file.display()))?;
+	let wrapped = line_length::rewrap(&content, policy);
+	if wrapped == content {
+		return Ok(true);
+	}
+	if check {
+		eprintln!("would rewrap: {}", file.display());
+		return Ok(false);
+	}
+	std::fs::write(file, wrapped).with_context(|| format!("cannot write {}", file.display()))?;
+	Ok(true)
+}
+
 fn run_oxfmt(path: &Path, oxfmt_cfg: &[(String, String)], check: bool) -> anyhow::Result<bool> {
 	let config_dir = if path.is_dir() {
 		path.to_path_buf()
@@ -156,35 +186,18 @@ fn is_oxfmt_ext(ext: &str) -> bool {
 	)
 }
 
-fn collect_rs_files(path: &Path, ignore: &[String]) -> anyhow::Result<Vec<PathBuf>> {
+pub fn collect_rs_files(path: &Path, ignore: &[String]) -> anyhow::Result<Vec<PathBuf>> {
+	collect_files(path, ignore, "rs")
+}
+
+fn collect_files(path: &Path, ignore: &[String], ext: &str) -> anyhow::Result<Vec<PathBuf>> {
 	if path.is_file() {
-		if path.extension().is_some_and(|ext| ext == "rs") {
+		if path.extension().is_some_and(|e| e == ext) {
 			return Ok(vec![path.to_path_buf()]);
 		}
 		return Ok(Vec::new());
 	}
-	let mut files = Vec::new();
-	walk_rs(path, ignore, &mut files)?;
-	files.sort();
+	let mut files = walk::files(path, ignore)?;
+	files.retain(|f| f.extension().is_some_and(|e| e == ext));
 	Ok(files)
 }
-
-fn walk_rs(dir: &Path, ignore: &[String], files: &mut Vec<PathBuf>) -> anyhow::Result<()> {
-	let entries =
-		std::fs::read_dir(dir).with_context(|| format!("cannot read directory: {}", dir.display()))?;
-	for entry in entries {
-		let entry = entry?;
-		let path = entry.path();
-		if path.is_dir() {
-			let name = entry.file_name();
-			let name_str = name.to_string_lossy();
-			if name_str.starts_with('.') || ignore.iter().any(|i| i == name_str.as_ref()) {
-				continue;
-			}
-			walk_rs(&path, ignore, files)?;
-		} else if path.extension().is_some_and(|ext| ext == "rs") {
-			files.push(path);
-		}
-	}
-	Ok(())
-}
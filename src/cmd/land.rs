@@ -37,6 +37,8 @@ pub fn execute(_args: Args) -> anyhow::Result<()> {
 		bail!("no described commit found at @-");
 	}
 
+	crate::cmd::changelog::require_documented(description, "@--", "@-", &[])?;
+
 	// move main bookmark
 	let set = std::process::Command::new("jj")
 		.args(["bookmark", "set", "main", "-r", change_id])
@@ -1,3 +1,4 @@
+pub mod changelog;
 pub mod check;
 pub mod commit;
 pub mod diff;
@@ -5,4 +6,5 @@ pub mod fmt;
 pub mod land;
 pub mod log;
 pub mod push;
+pub mod spec;
 pub mod status;
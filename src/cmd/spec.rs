@@ -0,0 +1,148 @@
+use anyhow::Context;
+use clap::Subcommand;
+
+use crate::cmd::check::requirements::{find_ids, scan_spec, spec_roots};
+use crate::cmd::check::{find_spec_dir, rel_path};
+use crate::cmd::fmt::collect_rs_files;
+use crate::config;
+
+#[derive(clap::Args)]
+pub struct Args {
+	#[command(subcommand)]
+	pub command: SpecCommand,
+}
+
+#[derive(Subcommand)]
+pub enum SpecCommand {
+	/// Show which requirement IDs are mentioned in Rust comments and tests
+	Trace,
+}
+
+pub fn execute(args: Args) -> anyhow::Result<()> {
+	match args.command {
+		SpecCommand::Trace => trace(),
+	}
+}
+
+/// A `REQ-*` mention in Rust source.
+struct Site {
+	id: String,
+	location: String,
+	kind: &'static str,
+}
+
+fn trace() -> anyhow::Result<()> {
+	let spec_dir = find_spec_dir()?;
+	let repo_root = spec_dir.parent().context("spec dir has no parent")?;
+
+	let mut defined: Vec<String> = Vec::new();
+	for root in spec_roots(&spec_dir, repo_root)? {
+		for mention in scan_spec(&root)? {
+			if mention.is_definition && !defined.contains(&mention.id) {
+				defined.push(mention.id);
+			}
+		}
+	}
+	defined.sort();
+
+	let ignore = config::load_fmt()?.ignore;
+	let mut sites = Vec::new();
+	for file in collect_rs_files(repo_root, &ignore)? {
+		let content =
+			std::fs::read_to_string(&file).with_context(|| format!("cannot read {}", file.display()))?;
+		sites.extend(scan_rust(&content, &rel_path(&file, repo_root)));
+	}
+
+	let mut covered = 0;
+	for id in &defined {
+		let found: Vec<&Site> = sites.iter().filter(|s| &s.id == id).collect();
+		if found.is_empty() {
+			println!("{id}  UNCOVERED");
+			continue;
+		}
+		covered += 1;
+		println!("{id}");
+		for site in found {
+			println!("  {} ({})", site.location, site.kind);
+		}
+	}
+
+	for site in sites.iter().filter(|s| !defined.contains(&s.id)) {
+		println!(
+			"{}  UNKNOWN — mentioned at {} ({})",
+			site.id, site.location, site.kind
+		);
+	}
+
+	eprintln!(
+		"{covered}/{} requirement(s) have implementing code",
+		defined.len()
+	);
+	Ok(())
+}
+
+/// Requirement IDs in comments and in test code (anything after
+/// `#[cfg(test)]`, or whole files under `tests/`).
+fn scan_rust(content: &str, location: &str) -> Vec<Site> {
+	let test_file = location.starts_with("tests/") || location.contains("/tests/");
+	let mut in_tests = test_file;
+	let mut in_block_comment = false;
+	let mut sites = Vec::new();
+
+	for (line_num, line) in content.lines().enumerate() {
+		let trimmed = line.trim_start();
+		if trimmed.starts_with("#[cfg(test)]") {
+			in_tests = true;
+		}
+
+		let comment = if in_block_comment {
+			if line.contains("*/") {
+				in_block_comment = false;
+			}
+			Some(line)
+		} else if let Some(start) = line.find("//") {
+			Some(&line[start..])
+		} else if let Some(start) = line.find("/*") {
+			in_block_comment = !line[start..].contains("*/");
+			Some(&line[start..])
+		} else {
+			None
+		};
+
+		let (text, kind) = match comment {
+			_ if in_tests => (line, "test"),
+			Some(comment) => (comment, "comment"),
+			None => continue,
+		};
+		for (id, _) in find_ids(text) {
+			sites.push(Site {
+				id: id.to_string(),
+				location: format!("{location}:{}", line_num + 1),
+				kind,
+			});
+		}
+	}
+
+	sites
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn rust_mentions() {
+		let source = "// Implements REQ-net-004.\nconst ID: &str = \"REQ-net-005\";\n/* covers\n   REQ-net-006 */\n#[cfg(test)]\nfn retry() { check(\"REQ-net-007\"); }\n";
+		let sites = scan_rust(source, "src/net.rs");
+		let found: Vec<(&str, &str)> = sites.iter().map(|s| (s.id.as_str(), s.kind)).collect();
+		assert_eq!(
+			found,
+			[
+				("REQ-net-004", "comment"),
+				("REQ-net-006", "comment"),
+				("REQ-net-007", "test")
+			]
+		);
+		assert_eq!(sites[0].location, "src/net.rs:1");
+	}
+}
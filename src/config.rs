@@ -100,3 +100,168 @@ pub fn find_config() -> Option<PathBuf> {
 		}
 	}
 }
+
+pub struct Table {
+	pub name: String,
+	entries: Vec<(String, toml::Value)>,
+}
+
+impl Table {
+	pub fn get(&self, key: &str) -> Option<&toml::Value> {
+		self.entries.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+	}
+
+	pub fn keys(&self) -> impl Iterator<Item = &str> {
+		self.entries.iter().map(|(k, _)| k.as_str())
+	}
+
+	pub fn get_str(&self, key: &str) -> Option<String> {
+		self.get(key)?.as_str().map(str::to_string)
+	}
+
+	pub fn get_list(&self, key: &str) -> Option<Vec<String>> {
+		self
+			.get(key)?
+			.as_array()?
+			.iter()
+			.map(|v| match v {
+				toml::Value::String(s) => Some(s.clone()),
+				toml::Value::Integer(_) | toml::Value::Float(_) | toml::Value::Boolean(_) => {
+					Some(v.to_string())
+				}
+				_ => None,
+			})
+			.collect()
+	}
+
+	pub fn get_bool(&self, key: &str) -> Option<bool> {
+		self.get(key)?.as_bool()
+	}
+
+	pub fn get_usize(&self, key: &str) -> Option<usize> {
+		usize::try_from(self.get(key)?.as_integer()?).ok()
+	}
+}
+
+/// Load every `[table]` and `[[array.table]]` from qwq.toml in file order,
+/// named by their dotted path; each element of an array of tables is its own
+/// `Table`.
+pub fn load_tables() -> anyhow::Result<Vec<Table>> {
+	let Some(path) = find_config() else {
+		return Ok(Vec::new());
+	};
+
+	let content =
+		std::fs::read_to_string(&path).with_context(|| format!("cannot read {}", path.display()))?;
+	parse_tables(&content).map_err(|e| anyhow::anyhow!("invalid {}: {e}", path.display()))
+}
+
+/// First table with the given name, e.g. `load_table("check.version")`.
+pub fn load_table(name: &str) -> anyhow::Result<Option<Table>> {
+	Ok(load_tables()?.into_iter().find(|t| t.name == name))
+}
+
+pub fn parse_tables(content: &str) -> anyhow::Result<Vec<Table>> {
+	let root: toml::Table = content.parse()?;
+	let mut tables = Vec::new();
+	flatten("", root, &mut tables);
+	Ok(tables)
+}
+
+/// Collect `table` and every table nested in it. Tables that only group
+/// others, like `check` in `[check.links]`, are not listed themselves.
+fn flatten(name: &str, table: toml::Table, tables: &mut Vec<Table>) {
+	let mut entries = Vec::new();
+	let mut nested = Vec::new();
+
+	for (key, value) in table {
+		let child = if name.is_empty() {
+			key.clone()
+		} else {
+			format!("{name}.{key}")
+		};
+		match value {
+			toml::Value::Table(t) => nested.push((child, vec![t])),
+			toml::Value::Array(items) if !items.is_empty() && items.iter().all(toml::Value::is_table) => {
+				let items = items
+					.into_iter()
+					.filter_map(|v| match v {
+						toml::Value::Table(t) => Some(t),
+						_ => None,
+					})
+					.collect();
+				nested.push((child, items));
+			}
+			value => entries.push((key, value)),
+		}
+	}
+
+	if !name.is_empty() && (nested.is_empty() || !entries.is_empty()) {
+		tables.push(Table {
+			name: name.to_string(),
+			entries,
+		});
+	}
+	for (child, items) in nested {
+		for item in items {
+			flatten(&child, item, tables);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn tables_and_values() -> anyhow::Result<()> {
+		let tables = parse_tables(
+			r#"
+[check]
+paths = ["docs/**"]  # trailing comment
+
+[check.line_length]
+
+[[check.forbidden]]
+pattern = "TODO" # not done
+paths = [
+	"spec/**",   # spec pages
+	'notes/*.md',
+]
+
+[[check.forbidden]]
+regex = "\\bfoo\\b"
+max = 1_000
+"#,
+		)?;
+		let names: Vec<&str> = tables.iter().map(|t| t.name.as_str()).collect();
+		assert_eq!(
+			names,
+			[
+				"check",
+				"check.line_length",
+				"check.forbidden",
+				"check.forbidden"
+			]
+		);
+
+		assert_eq!(
+			tables[0].get_list("paths"),
+			Some(vec!["docs/**".to_string()])
+		);
+		assert_eq!(tables[2].get_str("pattern").as_deref(), Some("TODO"));
+		assert_eq!(
+			tables[2].get_list("paths"),
+			Some(vec!["spec/**".to_string(), "notes/*.md".to_string()])
+		);
+		assert_eq!(tables[3].get_str("regex").as_deref(), Some("\\bfoo\\b"));
+		assert_eq!(tables[3].get_usize("max"), Some(1000));
+		assert_eq!(tables[3].get_bool("max"), None);
+		Ok(())
+	}
+
+	#[test]
+	fn invalid_toml() {
+		assert!(parse_tables("[check\npaths = 1").is_err());
+	}
+}
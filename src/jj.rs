@@ -0,0 +1,93 @@
+use std::path::Path;
+
+use anyhow::{Context, bail};
+
+/// Run jj in `root` and return its stdout, failing with jj's stderr on error.
+pub fn output(root: &Path, args: &[&str]) -> anyhow::Result<String> {
+	let out = std::process::Command::new("jj")
+		.args(args)
+		.current_dir(root)
+		.output()
+		.context("failed to execute jj")?;
+
+	if !out.status.success() {
+		let stderr = String::from_utf8_lossy(&out.stderr);
+		bail!("jj {} failed: {}", args.join(" "), stderr.trim());
+	}
+	Ok(String::from_utf8_lossy(&out.stdout).into_owned())
+}
+
+/// Content of `path` (relative to `root`) at `rev`, or `None` if the file
+/// does not exist in that revision.
+pub fn file_at(root: &Path, rev: &str, path: &str) -> anyhow::Result<Option<String>> {
+	let fileset = format!("root-file:\"{path}\"");
+	let out = std::process::Command::new("jj")
+		.args(["file", "show", "-r", rev, &fileset])
+		.current_dir(root)
+		.output()
+		.context("failed to execute jj file show")?;
+
+	let stdout = String::from_utf8_lossy(&out.stdout);
+	let stderr = String::from_utf8_lossy(&out.stderr);
+	match parse_file_show(out.status.success(), &stdout, &stderr) {
+		Some(content) => Ok(content),
+		None => bail!("jj file show -r {rev} {path} failed: {}", stderr.trim()),
+	}
+}
+
+/// Interpret `jj file show` output: `Some(None)` for a missing file, `None`
+/// for any other failure. Older jj fails with "No such path"; newer jj prints
+/// a warning such as "No matching entries for paths" and exits 0.
+fn parse_file_show(success: bool, stdout: &str, stderr: &str) -> Option<Option<String>> {
+	let missing =
+		stderr.contains("No such path") || (stdout.is_empty() && stderr.contains("Warning:"));
+	if missing {
+		Some(None)
+	} else if success {
+		Some(Some(stdout.to_string()))
+	} else {
+		None
+	}
+}
+
+/// Paths (relative to `root`) that differ between `from` and `to`.
+pub fn changed_files(root: &Path, from: &str, to: &str) -> anyhow::Result<Vec<String>> {
+	let out = output(root, &["diff", "--from", from, "--to", to, "--name-only"])?;
+	Ok(
+		out
+			.lines()
+			.filter(|l| !l.is_empty())
+			.map(|l| l.replace('\\', "/"))
+			.collect(),
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn file_show_output() {
+		assert_eq!(
+			parse_file_show(true, "0.4.0\n", ""),
+			Some(Some("0.4.0\n".to_string()))
+		);
+		assert_eq!(parse_file_show(true, "", ""), Some(Some(String::new())));
+		assert_eq!(
+			parse_file_show(
+				true,
+				"",
+				"Warning: No matching entries for paths: spec/VERSION\n"
+			),
+			Some(None)
+		);
+		assert_eq!(
+			parse_file_show(false, "", "Error: No such path: spec/VERSION\n"),
+			Some(None)
+		);
+		assert_eq!(
+			parse_file_show(false, "", "Error: Revision `x` doesn't exist\n"),
+			None
+		);
+	}
+}
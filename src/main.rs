@@ -1,5 +1,6 @@
 mod cmd;
 mod config;
+mod jj;
 mod run;
 
 fn main() {
@@ -16,6 +16,10 @@ enum Command {
 	Vcs(VcsArgs),
 	/// Format source files
 	Fmt(crate::cmd::fmt::Args),
+	/// Manage spec/CHANGELOG.md entries
+	Changelog(crate::cmd::changelog::Args),
+	/// Spec traceability reports
+	Spec(crate::cmd::spec::Args),
 }
 
 #[derive(clap::Args)]
@@ -53,5 +57,7 @@ pub fn run() -> anyhow::Result<()> {
 			VcsCommand::Status(args) => crate::cmd::status::execute(args),
 		},
 		Command::Fmt(args) => crate::cmd::fmt::execute(args),
+		Command::Changelog(args) => crate::cmd::changelog::execute(args),
+		Command::Spec(args) => crate::cmd::spec::execute(args),
 	}
 }